@@ -0,0 +1,135 @@
+/// Generates the in-process solution registry for the main binary.
+/// Every scaffolded `src/bin/NN.rs` and `src/bin/YYYY_NN.rs` is included as a module and registered with its puzzle.
+///
+/// Also generates the example tests of every puzzle from its manifest in `data/examples/NN.toml`,
+/// which are included into the solution binaries with `advent_of_code::example_tests!()`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let mut bins: Vec<String> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "rs" {
+                return None;
+            }
+            let stem = path.file_stem()?.to_str()?.to_string();
+            let day = match stem.split_once('_') {
+                Some((year, day)) if year.len() == 4 && year.parse::<u16>().is_ok() => day,
+                Some(_) => return None,
+                None => &stem,
+            };
+            match day.parse::<u8>() {
+                Ok(1..=25) if day.len() == 2 => Some(stem),
+                _ => None,
+            }
+        })
+        .collect();
+    bins.sort();
+
+    let mut lines: Vec<String> = vec![];
+
+    for bin in &bins {
+        let path = bin_dir.join(format!("{bin}.rs"));
+        lines.push("#[cfg(not(test))]".into());
+        lines.push("#[allow(dead_code)]".into());
+        lines.push(format!("#[path = {:?}]", path.display().to_string()));
+        lines.push(format!("mod day_{bin};"));
+    }
+
+    lines.push(String::new());
+    lines.push("/// Collects the solutions of every scaffolded puzzle.".into());
+    lines.push("pub fn registry() -> advent_of_code::template::registry::Registry {".into());
+    lines.push("    #[allow(unused_mut)]".into());
+    lines
+        .push("    let mut registry = advent_of_code::template::registry::Registry::new();".into());
+
+    for bin in &bins {
+        lines.push("    #[cfg(not(test))]".into());
+        lines.push(format!("    registry.register(day_{bin}::solution());"));
+    }
+
+    lines.push("    registry".into());
+    lines.push("}".into());
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), lines.join("\n")).unwrap();
+
+    let data_dir = Path::new(&manifest_dir).join("data");
+    let examples_out_dir = Path::new(&out_dir).join("examples");
+    fs::create_dir_all(&examples_out_dir).unwrap();
+
+    for bin in &bins {
+        let examples_dir = match bin.split_once('_') {
+            Some((year, _)) => data_dir.join(year).join("examples"),
+            None => data_dir.join("examples"),
+        };
+        if examples_dir.exists() {
+            println!("cargo:rerun-if-changed={}", examples_dir.display());
+        }
+
+        let day = bin.split_once('_').map_or(bin.as_str(), |(_, day)| day);
+        let manifest = examples_dir.join(format!("{day}.toml"));
+        let tests = generate_example_tests(&manifest);
+        fs::write(examples_out_dir.join(format!("{bin}.rs")), tests).unwrap();
+    }
+}
+
+/// Generates a test for every expected answer in a manifest, e.g. `part_one_example_1` for `part_one` of `08.1.txt`.
+fn generate_example_tests(manifest: &Path) -> String {
+    let Ok(content) = fs::read_to_string(manifest) else {
+        return String::new();
+    };
+    let table: toml::Table = content
+        .parse()
+        .unwrap_or_else(|e| panic!("invalid manifest {}: {e}", manifest.display()));
+
+    let mut lines: Vec<String> = vec![];
+    let examples = table.get("example").and_then(|v| v.as_array());
+
+    for example in examples.into_iter().flatten() {
+        let Some(file) = example.get("file").and_then(|v| v.as_str()) else {
+            panic!(
+                "expecting a file for every example in {}",
+                manifest.display()
+            );
+        };
+
+        // `08.txt` is the example, `08.1.txt` example 1.
+        let stem = file.trim_end_matches(".txt");
+        let suffix: String = match stem.split_once('.') {
+            Some((_, trial)) => format!("_{trial}"),
+            None => String::new(),
+        }
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+        for part in ["part_one", "part_two"] {
+            let Some(expected) = example.get(part) else {
+                continue;
+            };
+            let expected = match expected {
+                toml::Value::String(s) => s.clone(),
+                value => value.to_string(),
+            };
+            lines.push("#[test]".into());
+            lines.push(format!("fn {part}_example{suffix}() {{"));
+            lines.push(format!(
+                "    advent_of_code::template::examples::check(PUZZLE, {file:?}, {part}, {expected:?});"
+            ));
+            lines.push("}".into());
+            lines.push(String::new());
+        }
+    }
+
+    if !lines.is_empty() {
+        lines.insert(0, "use super::*;\n".into());
+    }
+    lines.join("\n")
+}
@@ -43,28 +43,25 @@ impl Reveal {
         // println!("{subset}");
         let value_for_key: HashMap<&str, u32> = subset
             .split(',')
-            .into_iter()
-            .map(|subset| maybe_key_value(subset))
-            .filter(|maybe| maybe.is_some())
-            .map(|maybe| maybe.unwrap())
+            .filter_map(|subset| maybe_key_value(subset))
             .collect::<HashMap<&str, u32>>();
         //println!("{:?}", value_for_key);
 
         let maybe_red_value = value_for_key.get("red");
         let red_value = match maybe_red_value {
-            Some(x) => x.clone(),
+            Some(x) => *x,
             None => 0,
         };
 
         let maybe_blue_value = value_for_key.get("blue");
         let blue_value = match maybe_blue_value {
-            Some(x) => x.clone(),
+            Some(x) => *x,
             None => 0,
         };
 
         let maybe_green_value = value_for_key.get("green");
         let green_value = match maybe_green_value {
-            Some(x) => x.clone(),
+            Some(x) => *x,
             None => 0,
         };
 
@@ -88,7 +85,7 @@ struct Game {
 impl Game {
     fn from_line(line: &str) -> Game {
         //println!("{line}");
-        let maybe_header = line.split(':').into_iter().next();
+        let maybe_header = line.split(':').next();
         let header = match maybe_header {
             Some(x) => x,
             None => panic!(),
@@ -102,7 +99,7 @@ impl Game {
         };
         //println!("{id}");
 
-        let maybe_body = line.split(':').into_iter().skip(1).next();
+        let maybe_body = line.split(':').nth(1);
         let body = match maybe_body {
             Some(x) => x,
             None => panic!(),
@@ -113,8 +110,7 @@ impl Game {
             id,
             reveals: body
                 .split(';')
-                .into_iter()
-                .map(|subset| Reveal::from_subset(subset))
+                .map(Reveal::from_subset)
                 .collect::<Vec<Reveal>>(),
         }
     }
@@ -131,9 +127,8 @@ impl Game {
 pub fn part_one(input: &str) -> Option<u32> {
     let total = input
         .split('\n')
-        .into_iter()
         .filter(|line| !line.is_empty())
-        .map(|line| Game::from_line(line))
+        .map(Game::from_line)
         .filter(|game| game.reveals.iter().all(|reveal| reveal.is_possible()))
         .map(|game| game.id)
         .sum();
@@ -144,28 +139,12 @@ pub fn part_one(input: &str) -> Option<u32> {
 pub fn part_two(input: &str) -> Option<u32> {
     let total = input
         .split('\n')
-        .into_iter()
         .filter(|line| !line.is_empty())
-        .map(|line| Game::from_line(line))
+        .map(Game::from_line)
         .map(|game| game.minimum_power())
         .sum();
 
     Some(total)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2286));
-    }
-}
+advent_of_code::example_tests!();
@@ -3,7 +3,7 @@ advent_of_code::solution!(3);
 use itertools::Itertools;
 
 fn is_symbol(c: char) -> bool {
-    !(c.is_digit(10) || c == '.')
+    !(c.is_ascii_digit() || c == '.')
 }
 
 fn sum_of_part_numbers(line: &str, previous_line: &str, next_line: &str) -> u32 {
@@ -18,7 +18,7 @@ fn sum_of_part_numbers(line: &str, previous_line: &str, next_line: &str) -> u32
                 .zip(next_line_with_padding.tuple_windows()),
         )
         .map(|((d, e, f), ((a, b, c), (g, h, i)))| {
-            e.is_digit(10)
+            e.is_ascii_digit()
                 && (is_symbol(a)
                     || is_symbol(b)
                     || is_symbol(c)
@@ -31,7 +31,7 @@ fn sum_of_part_numbers(line: &str, previous_line: &str, next_line: &str) -> u32
 
     line.chars()
         .zip(flags)
-        .group_by(|(c, _)| c.is_digit(10))
+        .group_by(|(c, _)| c.is_ascii_digit())
         .into_iter()
         .filter(|(key, _)| *key)
         .map(|(_, group)| group.collect::<Vec<(char, bool)>>())
@@ -65,19 +65,4 @@ pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4361));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-}
+advent_of_code::example_tests!();
@@ -80,7 +80,6 @@ impl FromStr for Card {
 pub fn part_one(input: &str) -> Option<u32> {
     let total = input
         .split('\n')
-        .into_iter()
         .filter(|line| !line.is_empty())
         .map(|line| Card::from_str(line).unwrap())
         .map(|card| card.score())
@@ -93,19 +92,4 @@ pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-}
+advent_of_code::example_tests!();
@@ -34,6 +34,7 @@ enum Card {
     Eight,
     Nine,
     Ten,
+    Jack,
     Queen,
     King,
     Ace,
@@ -42,7 +43,6 @@ enum Card {
 fn parse_card(input: &str) -> IResult<&str, Card> {
     let (i, c) = verify(anychar, |c| c.is_alphanumeric())(input)?;
     let card = match c {
-        'J' => Card::Joker,
         '2' => Card::Two,
         '3' => Card::Three,
         '4' => Card::Four,
@@ -52,6 +52,7 @@ fn parse_card(input: &str) -> IResult<&str, Card> {
         '8' => Card::Eight,
         '9' => Card::Nine,
         'T' => Card::Ten,
+        'J' => Card::Jack,
         'Q' => Card::Queen,
         'K' => Card::King,
         'A' => Card::Ace,
@@ -84,7 +85,7 @@ struct Hand {
 }
 
 fn get_category_jokers_wild(cards: &Cards) -> Category {
-    let counted_cards = (|| {
+    let counted_cards = {
         let joker_counter = cards
             .iter()
             .filter(|&card| *card == Card::Joker)
@@ -100,14 +101,14 @@ fn get_category_jokers_wild(cards: &Cards) -> Category {
         let joker_count = joker_counter[&Card::Joker];
         let most_common_then_highest_other_cards =
             other_card_counter.most_common_tiebreaker(|a, b| b.cmp(a));
-        let best_other_card = match most_common_then_highest_other_cards.iter().nth(0) {
-            Some((other_card, _)) => &other_card,
+        let best_other_card = match most_common_then_highest_other_cards.first() {
+            Some((other_card, _)) => other_card,
             None => &Card::Ace,
         };
         other_card_counter.update(vec![best_other_card; joker_count]);
 
         other_card_counter.most_common_tiebreaker(|a, b| b.cmp(a))
-    })();
+    };
 
     let card_counts = counted_cards
         .iter()
@@ -140,6 +141,25 @@ fn parse_hand(input: &str) -> IResult<&str, Hand> {
     ))
 }
 
+impl Hand {
+    /// Turns every Jack into a Joker, which is the weakest card but counts as any other for the category.
+    fn with_jokers(self) -> Self {
+        let cards: Cards = self
+            .cards
+            .into_iter()
+            .map(|card| match card {
+                Card::Jack => Card::Joker,
+                card => card,
+            })
+            .collect();
+        Hand {
+            category: get_category_jokers_wild(&cards),
+            cards,
+            bid: self.bid,
+        }
+    }
+}
+
 type Hands = Vec<Hand>;
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
@@ -175,6 +195,12 @@ impl FromStr for Game {
 }
 
 impl Game {
+    fn with_jokers(self) -> Self {
+        Game {
+            hands: self.hands.into_iter().map(Hand::with_jokers).collect(),
+        }
+    }
+
     fn ranked_hands(&self) -> Hands {
         let mut hands = self.hands.to_vec();
         hands.sort();
@@ -203,7 +229,7 @@ pub fn part_one(input: &str) -> Option<Winnings> {
 }
 
 pub fn part_two(input: &str) -> Option<Winnings> {
-    let game = Game::from_str(input).ok()?;
+    let game = Game::from_str(input).ok()?.with_jokers();
     // println!("{:?}", game);
 
     let total_winnings = game
@@ -220,19 +246,4 @@ pub fn part_two(input: &str) -> Option<Winnings> {
     Some(total_winnings)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6440));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5905));
-    }
-}
+advent_of_code::example_tests!();
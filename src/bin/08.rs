@@ -88,13 +88,7 @@ type Lookup = HashMap<Descriptor, Neighbours>;
 fn parse_lookup(input: &str) -> IResult<&str, Lookup> {
     // AAA = (BBB, CCC)\nBBB = (DDD, EEE)...
     let (i, entries) = separated_list1(char('\n'), parse_entry)(input)?;
-    Ok((
-        i,
-        entries
-            .into_iter()
-            .map(|(descriptor, neighbours)| (descriptor, neighbours))
-            .collect(),
-    ))
+    Ok((i, entries.into_iter().collect()))
 }
 
 #[derive(Debug, PartialEq)]
@@ -127,7 +121,7 @@ impl FromStr for Graph {
 impl Graph {
     fn neighbouring_descriptor_on_left(&self, descriptor: &Descriptor) -> Descriptor {
         self.lookup
-            .get(&descriptor)
+            .get(descriptor)
             .unwrap()
             .descriptor_on_left
             .clone()
@@ -135,7 +129,7 @@ impl Graph {
 
     fn neighbouring_descriptor_on_right(&self, descriptor: &Descriptor) -> Descriptor {
         self.lookup
-            .get(&descriptor)
+            .get(descriptor)
             .unwrap()
             .descriptor_on_right
             .clone()
@@ -173,19 +167,4 @@ pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-}
+advent_of_code::example_tests!();
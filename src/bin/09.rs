@@ -50,7 +50,7 @@ impl History {
                 if values.iter().all(|&value| value == 0) {
                     None
                 } else {
-                    let result = values.last().unwrap().clone();
+                    let result = *values.last().unwrap();
                     *values = differences(values);
                     Some(result)
                 }
@@ -69,7 +69,7 @@ impl History {
                 if values.iter().all(|&value| value == 0) {
                     None
                 } else {
-                    let result = values.first().unwrap().clone();
+                    let result = *values.first().unwrap();
                     *values = differences(values);
                     Some(result)
                 }
@@ -138,19 +138,4 @@ pub fn part_two(input: &str) -> Option<Value> {
     Some(total)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(114));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2));
-    }
-}
+advent_of_code::example_tests!();
@@ -131,11 +131,11 @@ fn parse_maze(input: &str) -> IResult<&str, Maze> {
     let width = rows.len();
     let depth = rows.first().map_or(0, |row| row.len());
     let mut data = Vec::new();
-    rows.iter().for_each(|row| data.extend_from_slice(&row));
+    rows.iter().for_each(|row| data.extend_from_slice(row));
     let tiles = Array2::from_shape_vec((width, depth), data).unwrap();
     let start_index = tiles
         .indexed_iter()
-        .find(|(_, &ref tile)| *tile == Tile::Start)
+        .find(|(_, tile)| **tile == Tile::Start)
         .map(|((i, j), _)| [i, j])
         .unwrap();
 
@@ -198,7 +198,7 @@ impl Maze {
     }
 
     fn tile_at(&self, index: &Index) -> Tile {
-        return self.tiles[*index];
+        self.tiles[*index]
     }
 
     fn tile_at_start(&self) -> Tile {
@@ -209,10 +209,7 @@ impl Maze {
             .filter(|(direction, index)| {
                 let tile = self.tile_at(index);
                 let from = reversed(*direction);
-                match tile {
-                    Tile::Link { from: a, to: b } if a == from || b == from => true,
-                    _ => false,
-                }
+                matches!(tile, Tile::Link { from: a, to: b } if a == from || b == from)
             })
             .map(|(direction, _)| direction)
             .sorted()
@@ -278,19 +275,4 @@ pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-}
+advent_of_code::example_tests!();
@@ -92,7 +92,7 @@ fn parse_image(input: &str) -> IResult<&str, Image> {
     let width = rows.len();
     let height = rows.first().map_or(0, |row| row.len());
     let mut data = Vec::new();
-    rows.iter().for_each(|row| data.extend_from_slice(&row));
+    rows.iter().for_each(|row| data.extend_from_slice(row));
     let pixels = Array2::from_shape_vec((width, height), data).unwrap();
     let empty_row_indexes = pixels
         .rows()
@@ -177,8 +177,8 @@ impl Image {
     }
 
     fn dilation(&self, from_index: &Index, to_index: &Index) -> Distance {
-        self.row_dilation(row_index(&from_index), row_index(&to_index))
-            + self.column_dilation(column_index(&from_index), column_index(&to_index))
+        self.row_dilation(row_index(from_index), row_index(to_index))
+            + self.column_dilation(column_index(from_index), column_index(to_index))
     }
 
     fn dilated_distance(&self, from_index: &Index, to_index: &Index) -> Distance {
@@ -230,19 +230,4 @@ pub fn part_two(input: &str) -> Option<Distance> {
     Some(total)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(374));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8410));
-    }
-}
+advent_of_code::example_tests!();
@@ -81,7 +81,7 @@ fn cached_arrangements(statuses: Statuses, runs: Runs) -> Arrangements {
         // For a match to exist the next run length statuses must not be Operational and the
         // following status must not be Damaged (so that the run ends).
         // Otherwise, a match is not possible.
-        return if statuses[..*run]
+        if statuses[..*run]
             .iter()
             .all(|status| *status != Status::Operational)
             && (statuses.len() == *run || statuses[*run] != Status::Damaged)
@@ -95,7 +95,7 @@ fn cached_arrangements(statuses: Statuses, runs: Runs) -> Arrangements {
             }
         } else {
             0
-        };
+        }
     }
 
     // If there are no unprocessed statuses...
@@ -159,15 +159,13 @@ impl Record {
             .flat_map(|_| {
                 statuses
                     .iter()
-                    .map(|status| *status)
+                    .copied()
                     .chain((0..1).map(|_| Status::Unknown))
             })
-            .chain(statuses.iter().map(|status| *status))
+            .chain(statuses.iter().copied())
             .collect::<Statuses>();
 
-        let unfolded_runs = (0..5)
-            .flat_map(|_| runs.iter().map(|run| *run))
-            .collect::<Runs>();
+        let unfolded_runs = (0..5).flat_map(|_| runs.iter().copied()).collect::<Runs>();
 
         Record {
             row: Row {
@@ -228,26 +226,11 @@ pub fn part_two(input: &str) -> Option<Arrangements> {
     let total = report
         .records
         .iter()
-        .map(|record| Record::unfolded(record))
+        .map(Record::unfolded)
         .map(|unfolded_record| unfolded_record.arrangements())
         .sum();
 
     Some(total)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(525152));
-    }
-}
+advent_of_code::example_tests!();
@@ -71,7 +71,7 @@ fn parse_pattern(input: &str) -> IResult<&str, Pattern> {
     let row_count = rows.len();
     let column_count = rows.first().map_or(0, |row| row.len());
     let mut data = Vec::new();
-    rows.iter().for_each(|row| data.extend_from_slice(&row));
+    rows.iter().for_each(|row| data.extend_from_slice(row));
     let features = Array2::from_shape_vec((row_count, column_count), data).unwrap();
 
     Ok((
@@ -88,33 +88,31 @@ type Score = usize;
 
 impl Pattern {
     fn horizontal_line_of_reflection_score(&self) -> Score {
-        match (1..self.row_count).find(|i| {
-            let h = min(i.abs_diff(0), i.abs_diff(self.row_count));
-            let features_above = self.features.slice(s![*i - h..*i;-1, ..]);
-            let features_below = self.features.slice(s![*i..*i + h, ..]);
-            features_above
-                .iter()
-                .zip(features_below.iter())
-                .all(|(a, b)| a == b)
-        }) {
-            Some(row_index) => row_index,
-            None => 0,
-        }
+        (1..self.row_count)
+            .find(|i| {
+                let h = min(i.abs_diff(0), i.abs_diff(self.row_count));
+                let features_above = self.features.slice(s![*i - h..*i;-1, ..]);
+                let features_below = self.features.slice(s![*i..*i + h, ..]);
+                features_above
+                    .iter()
+                    .zip(features_below.iter())
+                    .all(|(a, b)| a == b)
+            })
+            .unwrap_or_default()
     }
 
     fn vertical_line_of_reflection_score(&self) -> Score {
-        match (1..self.column_count).find(|j| {
-            let h = min(j.abs_diff(0), j.abs_diff(self.column_count));
-            let features_on_left = self.features.slice(s![.., *j - h..*j;-1]);
-            let features_on_right = self.features.slice(s![.., *j..*j + h]);
-            features_on_left
-                .iter()
-                .zip(features_on_right.iter())
-                .all(|(a, b)| a == b)
-        }) {
-            Some(column_index) => column_index,
-            None => 0,
-        }
+        (1..self.column_count)
+            .find(|j| {
+                let h = min(j.abs_diff(0), j.abs_diff(self.column_count));
+                let features_on_left = self.features.slice(s![.., *j - h..*j;-1]);
+                let features_on_right = self.features.slice(s![.., *j..*j + h]);
+                features_on_left
+                    .iter()
+                    .zip(features_on_right.iter())
+                    .all(|(a, b)| a == b)
+            })
+            .unwrap_or_default()
     }
 
     fn score(&self) -> Score {
@@ -163,19 +161,4 @@ pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(405));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-}
+advent_of_code::example_tests!();
@@ -80,7 +80,7 @@ fn parse_state(input: &str) -> IResult<&str, State> {
     let row_count = ranks.len();
     let column_count = ranks.first().map_or(0, |row| row.len());
     let mut data = Vec::new();
-    ranks.iter().for_each(|rank| data.extend_from_slice(&rank));
+    ranks.iter().for_each(|rank| data.extend_from_slice(rank));
     let maybe_rocks = Array2::from_shape_vec((row_count, column_count), data).unwrap();
 
     Ok((
@@ -116,59 +116,51 @@ impl State {
                 .maybe_rocks
                 .columns()
                 .into_iter()
-                .map(|column| {
+                .flat_map(|column| {
                     column
                         .into_iter()
-                        .group_by(|&maybe| maybe.map_or(false, |rock| rock == Rock::Cubic))
+                        .group_by(|&maybe| maybe.is_some_and(|rock| rock == Rock::Cubic))
                         .into_iter()
-                        .map(|(_, group)| group.sorted().rev().cloned())
-                        .flatten()
+                        .flat_map(|(_, group)| group.sorted().rev().cloned())
                         .collect::<Vec<_>>()
                 })
-                .flatten()
                 .collect::<Vec<MaybeRock>>(),
             Direction::South => self
                 .maybe_rocks
                 .columns()
                 .into_iter()
-                .map(|column| {
+                .flat_map(|column| {
                     column
                         .into_iter()
-                        .group_by(|&maybe| maybe.map_or(false, |rock| rock == Rock::Cubic))
+                        .group_by(|&maybe| maybe.is_some_and(|rock| rock == Rock::Cubic))
                         .into_iter()
-                        .map(|(_, group)| group.sorted().cloned())
-                        .flatten()
+                        .flat_map(|(_, group)| group.sorted().cloned())
                         .collect::<Vec<_>>()
                 })
-                .flatten()
                 .collect::<Vec<MaybeRock>>(),
             Direction::East => self
                 .maybe_rocks
                 .rows()
                 .into_iter()
-                .map(|row| {
+                .flat_map(|row| {
                     row.into_iter()
-                        .group_by(|&maybe| maybe.map_or(false, |rock| rock == Rock::Cubic))
+                        .group_by(|&maybe| maybe.is_some_and(|rock| rock == Rock::Cubic))
                         .into_iter()
-                        .map(|(_, group)| group.sorted().cloned())
-                        .flatten()
+                        .flat_map(|(_, group)| group.sorted().cloned())
                         .collect::<Vec<_>>()
                 })
-                .flatten()
                 .collect::<Vec<MaybeRock>>(),
             Direction::West => self
                 .maybe_rocks
                 .rows()
                 .into_iter()
-                .map(|row| {
+                .flat_map(|row| {
                     row.into_iter()
-                        .group_by(|&maybe| maybe.map_or(false, |rock| rock == Rock::Cubic))
+                        .group_by(|&maybe| maybe.is_some_and(|rock| rock == Rock::Cubic))
                         .into_iter()
-                        .map(|(_, group)| group.sorted().rev().cloned())
-                        .flatten()
+                        .flat_map(|(_, group)| group.sorted().rev().cloned())
                         .collect::<Vec<_>>()
                 })
-                .flatten()
                 .collect::<Vec<MaybeRock>>(),
         };
 
@@ -237,19 +229,4 @@ pub fn part_two(input: &str) -> Option<Load> {
     Some(final_state.load())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(136));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(64));
-    }
-}
+advent_of_code::example_tests!();
@@ -111,7 +111,7 @@ fn parse_grid(input: &str) -> IResult<&str, Grid> {
     let row_count = ranks.len();
     let column_count = ranks.first().map_or(0, |row| row.len());
     let mut data = Vec::new();
-    ranks.iter().for_each(|rank| data.extend_from_slice(&rank));
+    ranks.iter().for_each(|rank| data.extend_from_slice(rank));
     let tiles = Array2::from_shape_vec((row_count, column_count), data).unwrap();
 
     Ok((
@@ -283,7 +283,7 @@ impl<'a> Iterator for GridIterator<'a> {
             None => self.will_visit_states.push(state.propagated_state()),
         }
 
-        return Some(item);
+        Some(item)
     }
 }
 
@@ -291,10 +291,10 @@ impl Grid {
     fn tile_at(&self, index: &Index) -> &Tile {
         let i = index[0] as usize;
         let j = index[1] as usize;
-        return &self.tiles[[i, j]];
+        &self.tiles[[i, j]]
     }
 
-    fn indexed_iter_from(&self, state: State) -> GridIterator {
+    fn indexed_iter_from(&self, state: State) -> GridIterator<'_> {
         GridIterator {
             will_visit_states: vec![state],
             did_visit_states: HashSet::new(),
@@ -370,19 +370,4 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(max_energised_count)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(46));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(51));
-    }
-}
+advent_of_code::example_tests!();
@@ -101,7 +101,7 @@ fn parse_city(input: &str) -> IResult<&str, City> {
     let mut data = Vec::new();
     streets
         .iter()
-        .for_each(|street| data.extend_from_slice(&street));
+        .for_each(|street| data.extend_from_slice(street));
     let blocks = Array2::from_shape_vec((street_count, avenue_count), data).unwrap();
 
     Ok((
@@ -132,7 +132,7 @@ impl FromStr for City {
 
 impl City {
     fn block_at(&self, index: Index) -> &Block {
-        return &self.blocks[index];
+        &self.blocks[index]
     }
 
     fn maybe_state_ahead(&self, state: &State) -> MaybeState {
@@ -383,19 +383,4 @@ pub fn part_two(input: &str) -> Option<u32> {
     ))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(102));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(94));
-    }
-}
+advent_of_code::example_tests!();
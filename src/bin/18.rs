@@ -142,26 +142,15 @@ impl FromStr for Plan {
 
 type Coordinate = isize;
 
-#[derive(Copy, Clone, Debug, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 struct Index {
     x: Coordinate, // Increases rightwards.
     y: Coordinate, // Increase upwards.
 }
 
-impl PartialEq for Index {
-    fn eq(&self, other: &Self) -> bool {
-        self.y == other.y && self.x == other.x
-    }
-}
-
-impl Eq for Index {}
-
 impl PartialOrd for Index {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match other.y.partial_cmp(&self.y) {
-            Some(Ordering::Equal) => self.x.partial_cmp(&other.x),
-            result @ _ => result,
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -169,7 +158,7 @@ impl Ord for Index {
     fn cmp(&self, other: &Self) -> Ordering {
         match other.y.cmp(&self.y) {
             Ordering::Equal => self.x.cmp(&other.x),
-            result @ _ => result,
+            result => result,
         }
     }
 }
@@ -271,7 +260,7 @@ impl Lagoon {
             .take(self.vertices.len())
             .map(|(a, b, c)| b.x * (c.y - a.y))
             .sum::<Coordinate>()
-            .abs() as Capacity;
+            .unsigned_abs();
 
         1 + ((self.perimeter + twice_area) / 2)
     }
@@ -287,7 +276,7 @@ impl Lagoon {
             .take(self.decoded_vertices.len())
             .map(|(a, b, c)| b.x * (c.y - a.y))
             .sum::<Coordinate>()
-            .abs() as Capacity;
+            .unsigned_abs();
 
         1 + ((self.decoded_perimeter + twice_area) / 2)
     }
@@ -313,35 +302,4 @@ pub fn part_two(input: &str) -> Option<Capacity> {
     Some(lagoon.decoded_capacity())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(62));
-    }
-
-    #[test]
-    fn test_part_one_trial_one() {
-        let result = part_one(&advent_of_code::template::read_file_for_trial(
-            "examples", DAY, "1",
-        ));
-        assert_eq!(result, Some(15));
-    }
-
-    #[test]
-    fn test_part_one_trial_two() {
-        let result = part_one(&advent_of_code::template::read_file_for_trial(
-            "examples", DAY, "2",
-        ));
-        assert_eq!(result, Some(32));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(952408144115));
-    }
-}
+advent_of_code::example_tests!();
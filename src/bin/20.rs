@@ -271,7 +271,7 @@ fn parse_configuration(input: &str) -> IResult<&str, Configuration> {
     // must collect these sender names for each receiver name. Since we don't currently have a way
     // to test whether a given module name is associated with a conjunction relay we just collect
     // them all...
-    let sender_names_for_name = (|| {
+    let sender_names_for_name = {
         let mut result = NamesForName::new();
         modules.iter().for_each(|module| {
             module.receiver_names.iter().for_each(|receiver_name| {
@@ -284,7 +284,7 @@ fn parse_configuration(input: &str) -> IResult<&str, Configuration> {
         });
 
         result.clone()
-    })();
+    };
 
     let module_for_name = modules
         .iter()
@@ -349,7 +349,7 @@ impl<'a> Iterator for MessageIterator<'a> {
 }
 
 impl Configuration {
-    fn messages(&mut self) -> MessageIterator {
+    fn messages(&mut self) -> MessageIterator<'_> {
         let mut message_queue = MessageQueue::new();
         message_queue.push_back(Message {
             sender_name: Name::from("button"),
@@ -416,35 +416,4 @@ pub fn part_two(input: &str) -> Option<Count> {
     Some(count)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(32000000));
-    }
-
-    #[test]
-    fn test_part_one_trial_one() {
-        let result = part_one(&advent_of_code::template::read_file_for_trial(
-            "examples", DAY, "1",
-        ));
-        assert_eq!(result, Some(11687500));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-
-    #[test]
-    fn test_part_one_trial_two() {
-        let result = part_two(&advent_of_code::template::read_file_for_trial(
-            "examples", DAY, "2",
-        ));
-        assert_eq!(result, Some(1));
-    }
-}
+advent_of_code::example_tests!();
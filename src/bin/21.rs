@@ -82,7 +82,7 @@ fn parse_map(input: &str) -> IResult<&str, Map> {
     let row_count = ranks.len();
     let column_count = ranks.first().map_or(0, |row| row.len());
     let mut data = Vec::new();
-    ranks.iter().for_each(|rank| data.extend_from_slice(&rank));
+    ranks.iter().for_each(|rank| data.extend_from_slice(rank));
     let plots = Array2::from_shape_vec((row_count, column_count), data).unwrap();
     let start_index = plots
         .indexed_iter()
@@ -117,7 +117,7 @@ impl FromStr for Map {
 
 impl Map {
     fn plot_at(&self, index: &Index) -> &Plot {
-        return &self.plots[*index];
+        &self.plots[*index]
     }
 
     fn maybe_state_one_step_north(&self, state: &State) -> MaybeState {
@@ -203,7 +203,7 @@ fn parse_farm(input: &str) -> IResult<&str, Farm> {
     let row_count = ranks.len();
     let column_count = ranks.first().map_or(0, |row| row.len());
     let mut data = Vec::new();
-    ranks.iter().for_each(|rank| data.extend_from_slice(&rank));
+    ranks.iter().for_each(|rank| data.extend_from_slice(rank));
     let plots = Array2::from_shape_vec((row_count, column_count), data).unwrap();
     let start_index = plots
         .indexed_iter()
@@ -242,7 +242,7 @@ impl Farm {
         let i = x.rem_euclid(self.row_count as isize);
         let j = y.rem_euclid(self.column_count as isize);
         let index = [i as usize, j as usize];
-        return &self.plots[index];
+        &self.plots[index]
     }
 
     fn node_one_step_north(&self, node: &Node) -> Node {
@@ -292,7 +292,8 @@ impl Farm {
     fn reachable_plot_count(&self, step_count: StepCount) -> PlotCount {
         let [i, j] = self.start_index;
         let start_location = [i as isize, j as isize];
-        let count = bfs_reach(
+
+        bfs_reach(
             Node {
                 location: start_location,
                 step_count: 0,
@@ -301,17 +302,15 @@ impl Farm {
         )
         .skip_while(|node| node.step_count < step_count)
         .take_while(|node| node.step_count == step_count)
-        .count();
-
-        count
+        .count()
     }
 }
 
-pub fn part_one(input: &str) -> Option<PlotCount> {
+/// Counts the plots that can be reached in exactly `target_step_count` steps.
+fn reachable_plot_count(input: &str, target_step_count: StepCount) -> Option<PlotCount> {
     let map = Map::from_str(input).ok()?;
     // println!("{:?}\n", map);
 
-    let target_step_count = 64;
     let reachable_plot_count = bfs_reach(
         State {
             index: map.start_index,
@@ -327,6 +326,10 @@ pub fn part_one(input: &str) -> Option<PlotCount> {
     Some(reachable_plot_count)
 }
 
+pub fn part_one(input: &str) -> Option<PlotCount> {
+    reachable_plot_count(input, 64)
+}
+
 pub fn part_two(input: &str) -> Option<StepCount> {
     let farm = Farm::from_str(input).ok()?;
     // println!("{:?}\n", farm);
@@ -382,19 +385,16 @@ pub fn part_two(input: &str) -> Option<StepCount> {
     Some(huge_count)
 }
 
+advent_of_code::example_tests!();
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // the example of the description walks 6 steps instead of the 64 of part one.
     #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(42));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(42));
+    fn part_one_example_after_6_steps() {
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
+        assert_eq!(reachable_plot_count(&input, 6), Some(16));
     }
 }
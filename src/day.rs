@@ -1,7 +1,10 @@
+use std::collections::BTreeSet;
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
+
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
 ///
 /// # Display
@@ -12,7 +15,8 @@ use std::str::FromStr;
 /// let day = Day::new(8).unwrap();
 /// assert_eq!(day.to_string(), "08")
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
 pub struct Day(u8);
 
 impl Day {
@@ -55,6 +59,20 @@ impl PartialOrd<u8> for Day {
     }
 }
 
+impl TryFrom<u8> for Day {
+    type Error = DayFromStrError;
+
+    fn try_from(day: u8) -> Result<Self, Self::Error> {
+        Self::new(day).ok_or(DayFromStrError)
+    }
+}
+
+impl From<Day> for u8 {
+    fn from(day: Day) -> Self {
+        day.into_inner()
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl FromStr for Day {
@@ -80,6 +98,100 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
+/// A set of days, e.g. the days a command runs.
+///
+/// # Parsing
+/// Days and ranges are separated by commas. `1-5` includes both ends, `12..` runs to the 25th and `..5` starts at the 1st.
+///
+/// ```
+/// # use advent_of_code::DaySet;
+/// let days: DaySet = "1-3,9,24..".parse().unwrap();
+/// let days: Vec<u8> = days.iter().map(|day| day.into_inner()).collect();
+/// assert_eq!(days, vec![1, 2, 3, 9, 24, 25]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet(BTreeSet<Day>);
+
+impl DaySet {
+    /// Every day of advent.
+    pub fn all() -> Self {
+        Self(all_days().collect())
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        self.0.contains(&day)
+    }
+
+    /// Iterates the days in order.
+    pub fn iter(&self) -> impl Iterator<Item = Day> + '_ {
+        self.0.iter().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |s: &str| s.trim().parse::<Day>().map_err(|_| DaySetFromStrError);
+
+        let mut days = BTreeSet::new();
+
+        for item in s.split(',') {
+            let (first, last) = if let Some((first, last)) = item.split_once("..") {
+                let first = if first.trim().is_empty() {
+                    Day(1)
+                } else {
+                    parse_day(first)?
+                };
+                let last = if last.trim().is_empty() {
+                    Day(25)
+                } else {
+                    parse_day(last)?
+                };
+                (first, last)
+            } else if let Some((first, last)) = item.split_once('-') {
+                (parse_day(first)?, parse_day(last)?)
+            } else {
+                let day = parse_day(item)?;
+                (day, day)
+            };
+
+            if first > last {
+                return Err(DaySetFromStrError);
+            }
+
+            // NOTE: both ends were verified to be valid days.
+            days.extend((first.0..=last.0).map(Day));
+        }
+
+        Ok(Self(days))
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub struct DaySetFromStrError;
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "expecting days between 1 and 25 or ranges such as `1-5` or `12..`, separated by commas",
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// An iterator that yields every day of advent from the 1st to the 25th.
 pub fn all_days() -> AllDays {
     AllDays::new()
@@ -134,7 +246,39 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DaySet};
+
+    fn parse_days(s: &str) -> Option<Vec<u8>> {
+        let days: DaySet = s.parse().ok()?;
+        Some(days.iter().map(Day::into_inner).collect())
+    }
+
+    #[test]
+    fn parses_day_sets() {
+        assert_eq!(parse_days("3"), Some(vec![3]));
+        assert_eq!(
+            parse_days("1-5,9,12.."),
+            Some(vec![
+                1, 2, 3, 4, 5, 9, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25
+            ])
+        );
+        assert_eq!(parse_days("..3, 2-4"), Some(vec![1, 2, 3, 4]));
+        assert_eq!(
+            parse_days(".."),
+            Some(all_days().map(Day::into_inner).collect())
+        );
+        assert_eq!(parse_days("7-7"), Some(vec![7]));
+    }
+
+    #[test]
+    fn rejects_invalid_day_sets() {
+        assert_eq!(parse_days(""), None);
+        assert_eq!(parse_days("0"), None);
+        assert_eq!(parse_days("1,26"), None);
+        assert_eq!(parse_days("5-3"), None);
+        assert_eq!(parse_days("1-"), None);
+        assert_eq!(parse_days("1,,2"), None);
+    }
 
     #[test]
     fn all_days_iterator() {
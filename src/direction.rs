@@ -0,0 +1,246 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::grid::Coord;
+
+/// One of the four directions of a grid, with north pointing up.
+///
+/// Offsets are [`Coord`]s, so walking a [`Grid`](crate::grid::Grid) is `grid.step(index, direction.offset())`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// The direction something is travelling in, e.g. a crucible or a beam of light.
+pub type Heading = Direction;
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Turns by 90 degrees counter-clockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    /// Turns by 90 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// Turns around.
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// The direction after bouncing off a mirror, e.g. a beam heading east is reflected north by `/`.
+    #[must_use]
+    pub fn reflect(self, mirror: Mirror) -> Self {
+        match (mirror, self) {
+            (Mirror::Slash, Direction::North | Direction::South)
+            | (Mirror::Backslash, Direction::East | Direction::West) => self.turn_right(),
+            (Mirror::Slash, Direction::East | Direction::West)
+            | (Mirror::Backslash, Direction::North | Direction::South) => self.turn_left(),
+        }
+    }
+
+    /// The offset of a single step as `[row, column]`, e.g. `[-1, 0]` for north.
+    #[must_use]
+    pub fn offset(self) -> Coord {
+        match self {
+            Direction::North => [-1, 0],
+            Direction::East => [0, 1],
+            Direction::South => [1, 0],
+            Direction::West => [0, -1],
+        }
+    }
+
+    #[must_use]
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    #[must_use]
+    pub fn is_horizontal(self) -> bool {
+        !self.is_vertical()
+    }
+}
+
+/// Parses `U/D/L/R`, `N/E/S/W` and the arrows `^>v<`.
+impl TryFrom<char> for Direction {
+    type Error = DirectionFromStrError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | 'N' | '^' => Ok(Direction::North),
+            'R' | 'E' | '>' => Ok(Direction::East),
+            'D' | 'S' | 'v' => Ok(Direction::South),
+            'L' | 'W' | '<' => Ok(Direction::West),
+            _ => Err(DirectionFromStrError),
+        }
+    }
+}
+
+/// Writes the direction as an arrow, `^>v<`.
+impl From<Direction> for char {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Direction {
+    type Err = DirectionFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Self::try_from(c),
+            _ => Err(DirectionFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Direction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirectionFromStrError;
+
+impl Error for DirectionFromStrError {}
+
+impl Display for DirectionFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a direction of `U/D/L/R`, `N/E/S/W` or `^>v<`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A mirror that reflects by 90 degrees, see [`Direction::reflect`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Mirror {
+    /// `/`, from the bottom left to the top right.
+    Slash,
+    /// `\`, from the top left to the bottom right.
+    Backslash,
+}
+
+impl TryFrom<char> for Mirror {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '/' => Ok(Mirror::Slash),
+            '\\' => Ok(Mirror::Backslash),
+            _ => Err(c),
+        }
+    }
+}
+
+impl From<Mirror> for char {
+    fn from(mirror: Mirror) -> Self {
+        match mirror {
+            Mirror::Slash => '/',
+            Mirror::Backslash => '\\',
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, DirectionFromStrError, Mirror};
+    use crate::grid::NEIGHBOURS_4;
+
+    #[test]
+    fn turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().reverse(), direction);
+        }
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::East.reverse(), Direction::West);
+    }
+
+    #[test]
+    fn reflects_off_mirrors() {
+        let reflect =
+            |c: char, direction: Direction| direction.reflect(Mirror::try_from(c).unwrap());
+
+        assert_eq!(reflect('/', Direction::East), Direction::North);
+        assert_eq!(reflect('/', Direction::North), Direction::East);
+        assert_eq!(reflect('/', Direction::West), Direction::South);
+        assert_eq!(reflect('/', Direction::South), Direction::West);
+        assert_eq!(reflect('\\', Direction::East), Direction::South);
+        assert_eq!(reflect('\\', Direction::South), Direction::East);
+        assert_eq!(reflect('\\', Direction::West), Direction::North);
+        assert_eq!(reflect('\\', Direction::North), Direction::West);
+        assert_eq!(Mirror::try_from('|'), Err('|'));
+    }
+
+    #[test]
+    fn has_unit_offsets() {
+        let offsets: Vec<_> = Direction::ALL.map(Direction::offset).to_vec();
+        assert_eq!(offsets, NEIGHBOURS_4.to_vec());
+        assert!(Direction::North.is_vertical() && Direction::West.is_horizontal());
+    }
+
+    #[test]
+    fn parses_directions() {
+        for (chars, direction) in [
+            ("UN^", Direction::North),
+            ("RE>", Direction::East),
+            ("DSv", Direction::South),
+            ("LW<", Direction::West),
+        ] {
+            for c in chars.chars() {
+                assert_eq!(Direction::try_from(c), Ok(direction));
+                assert_eq!(c.to_string().parse(), Ok(direction));
+            }
+            assert_eq!(direction.to_string().parse(), Ok(direction));
+        }
+
+        assert_eq!(Direction::try_from('x'), Err(DirectionFromStrError));
+        assert_eq!("".parse::<Direction>(), Err(DirectionFromStrError));
+        assert_eq!("UD".parse::<Direction>(), Err(DirectionFromStrError));
+    }
+}
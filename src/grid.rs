@@ -0,0 +1,385 @@
+/// A rectangular map of cells, the shape most puzzle inputs come in.
+///
+/// Cells are parsed from characters with [`TryFrom<char>`] and stored row-major in an [`Array2`],
+/// so a grid day starts from `let grid: Grid<Tile> = input.parse()?;`.
+use std::fmt::Display;
+use std::ops;
+use std::str::FromStr;
+
+use ndarray::Array2;
+use nom::error::ErrorKind;
+
+use crate::template::SolutionError;
+
+/// The position of a cell as `[row, column]`, starting at the top left.
+pub type Index = [usize; 2];
+
+/// A signed position as `[row, column]`, which may lie outside of the grid.
+pub type Coord = [isize; 2];
+
+/// The offsets to the neighbours that share an edge with a cell: north, east, south and west.
+pub const NEIGHBOURS_4: [Coord; 4] = [[-1, 0], [0, 1], [1, 0], [0, -1]];
+
+/// The offsets to the neighbours that share an edge or a corner with a cell, clockwise from north.
+pub const NEIGHBOURS_8: [Coord; 8] = [
+    [-1, 0],
+    [-1, 1],
+    [0, 1],
+    [1, 1],
+    [1, 0],
+    [1, -1],
+    [0, -1],
+    [-1, -1],
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its rows, which all need to have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, SolutionError> {
+        let row_count = rows.len();
+        let column_count = rows.first().map_or(0, Vec::len);
+
+        if column_count == 0 {
+            return Err(SolutionError::Invalid("the grid is empty".into()));
+        }
+
+        if let Some((row, cells)) = rows
+            .iter()
+            .enumerate()
+            .find(|(_, cells)| cells.len() != column_count)
+        {
+            return Err(SolutionError::Invalid(format!(
+                "row {} of the grid has {} cells, expecting {column_count}",
+                row + 1,
+                cells.len()
+            )));
+        }
+
+        let cells = Array2::from_shape_vec(
+            (row_count, column_count),
+            rows.into_iter().flatten().collect(),
+        )
+        .expect("every row should have the same length");
+        Ok(Self { cells })
+    }
+
+    #[must_use]
+    pub fn row_count(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    #[must_use]
+    pub fn column_count(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    /// The cells of the grid, for the operations of [`ndarray`], e.g. iterating over columns.
+    #[must_use]
+    pub fn cells(&self) -> &Array2<T> {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut Array2<T> {
+        &mut self.cells
+    }
+
+    #[must_use]
+    pub fn into_cells(self) -> Array2<T> {
+        self.cells
+    }
+
+    #[must_use]
+    pub fn get(&self, index: Index) -> Option<&T> {
+        self.cells.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: Index) -> Option<&mut T> {
+        self.cells.get_mut(index)
+    }
+
+    /// Returns the cell at a signed coordinate, or [`None`] if it lies outside of the grid.
+    #[must_use]
+    pub fn get_signed(&self, coord: Coord) -> Option<&T> {
+        self.to_index(coord).and_then(|index| self.get(index))
+    }
+
+    /// Returns the cell at a signed coordinate of the grid repeated infinitely in every direction.
+    #[must_use]
+    pub fn get_wrapping(&self, coord: Coord) -> &T {
+        &self.cells[self.wrap(coord)]
+    }
+
+    /// Whether a signed coordinate lies inside of the grid.
+    #[must_use]
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.to_index(coord).is_some()
+    }
+
+    /// Converts a signed coordinate to the index of a cell, or [`None`] if it lies outside of the grid.
+    #[must_use]
+    pub fn to_index(&self, [row, column]: Coord) -> Option<Index> {
+        let row = usize::try_from(row).ok()?;
+        let column = usize::try_from(column).ok()?;
+        (row < self.row_count() && column < self.column_count()).then_some([row, column])
+    }
+
+    /// Moves from `index` by `offset`. Returns [`None`] if the step leaves the grid.
+    #[must_use]
+    pub fn step(&self, index: Index, offset: Coord) -> Option<Index> {
+        self.to_index(offset_by(index, offset))
+    }
+
+    /// Moves from `index` by `offset`, re-entering the grid on the opposite edge when leaving it.
+    #[must_use]
+    pub fn step_wrapping(&self, index: Index, offset: Coord) -> Index {
+        self.wrap(offset_by(index, offset))
+    }
+
+    /// The neighbours of a cell that share an edge with it and lie inside of the grid, clockwise from north.
+    pub fn neighbours4(&self, index: Index) -> impl Iterator<Item = Index> + '_ {
+        NEIGHBOURS_4
+            .into_iter()
+            .filter_map(move |offset| self.step(index, offset))
+    }
+
+    /// The neighbours of a cell that share an edge or a corner with it and lie inside of the grid, clockwise from north.
+    pub fn neighbours8(&self, index: Index) -> impl Iterator<Item = Index> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |offset| self.step(index, offset))
+    }
+
+    /// Iterates over the cells and their indices, row by row.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Index, &T)> {
+        self.cells
+            .indexed_iter()
+            .map(|((row, column), cell)| ([row, column], cell))
+    }
+
+    /// Returns the index of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Index> {
+        self.indexed_iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the index of the first cell, row by row, that equals `value`, e.g. the start tile.
+    pub fn find(&self, value: &T) -> Option<Index>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// Returns the indices of every cell that equals `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Index> + 'a
+    where
+        T: PartialEq,
+    {
+        self.indexed_iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(index, _)| index)
+    }
+
+    /// Creates a grid of the same shape with every cell mapped by `func`, e.g. to track visited cells.
+    #[must_use]
+    pub fn map<U>(&self, func: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.map(func),
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn wrap(&self, [row, column]: Coord) -> Index {
+        // `rem_euclid` of a positive divisor is never negative.
+        #[allow(clippy::cast_sign_loss)]
+        [
+            row.rem_euclid(self.row_count() as isize) as usize,
+            column.rem_euclid(self.column_count() as isize) as usize,
+        ]
+    }
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn offset_by([row, column]: Index, [row_offset, column_offset]: Coord) -> Coord {
+    [row as isize + row_offset, column as isize + column_offset]
+}
+
+impl<T> From<Array2<T>> for Grid<T> {
+    fn from(cells: Array2<T>) -> Self {
+        Self { cells }
+    }
+}
+
+impl<T> ops::Index<Index> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: Index) -> &Self::Output {
+        &self.cells[index]
+    }
+}
+
+impl<T> ops::IndexMut<Index> for Grid<T> {
+    fn index_mut(&mut self, index: Index) -> &mut Self::Output {
+        &mut self.cells[index]
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Parses a grid with a row per line and a cell per character.
+/// Characters that are not a valid cell fail with a [`SolutionError::Parse`] pointing at them.
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = SolutionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s
+            .trim_end_matches(['\n', '\r'])
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(column, c)| {
+                        T::try_from(c).map_err(|_| SolutionError::Parse {
+                            kind: ErrorKind::Char,
+                            line: row + 1,
+                            column: column + 1,
+                            source_line: line.to_string(),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::from_rows(rows)
+    }
+}
+
+/// Writes the grid in the format it was parsed from, a line per row.
+impl<T: Copy + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.rows() {
+            let line: String = row.iter().map(|&cell| cell.into()).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use nom::error::ErrorKind;
+
+    use super::{Grid, NEIGHBOURS_4};
+    use crate::template::SolutionError;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Garden,
+        Rock,
+        Start,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = char;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Self::Garden),
+                '#' => Ok(Self::Rock),
+                'S' => Ok(Self::Start),
+                _ => Err(c),
+            }
+        }
+    }
+
+    impl From<Tile> for char {
+        fn from(tile: Tile) -> Self {
+            match tile {
+                Tile::Garden => '.',
+                Tile::Rock => '#',
+                Tile::Start => 'S',
+            }
+        }
+    }
+
+    const MAP: &str = "..#\n.S.\n#..\n";
+
+    #[test]
+    fn parses_and_displays_grids() {
+        let grid: Grid<Tile> = MAP.parse().unwrap();
+        assert_eq!((grid.row_count(), grid.column_count()), (3, 3));
+        assert_eq!(grid[[0, 2]], Tile::Rock);
+        assert_eq!(grid.to_string(), MAP);
+        assert_eq!(grid.to_string().parse::<Grid<Tile>>().unwrap(), grid);
+        assert_eq!("..#\r\n.S.\r\n#..".parse::<Grid<Tile>>().unwrap(), grid);
+    }
+
+    #[test]
+    fn rejects_invalid_grids() {
+        assert_eq!(
+            "..#\n.X.\n".parse::<Grid<Tile>>(),
+            Err(SolutionError::Parse {
+                kind: ErrorKind::Char,
+                line: 2,
+                column: 2,
+                source_line: ".X.".into(),
+            })
+        );
+        assert_eq!(
+            "..#\n.S\n".parse::<Grid<Tile>>(),
+            Err(SolutionError::Invalid(
+                "row 2 of the grid has 2 cells, expecting 3".into()
+            ))
+        );
+        assert!("\n".parse::<Grid<Tile>>().is_err());
+    }
+
+    #[test]
+    fn finds_cells() {
+        let grid: Grid<Tile> = MAP.parse().unwrap();
+        assert_eq!(grid.find(&Tile::Start), Some([1, 1]));
+        assert_eq!(
+            grid.find_all(&Tile::Rock).collect::<Vec<_>>(),
+            vec![[0, 2], [2, 0]]
+        );
+        assert_eq!(grid.position(|tile| *tile != Tile::Garden), Some([0, 2]));
+        assert_eq!(grid.map(|tile| *tile == Tile::Rock)[[2, 0]], true);
+    }
+
+    #[test]
+    fn looks_up_neighbours() {
+        let grid: Grid<Tile> = MAP.parse().unwrap();
+        assert_eq!(grid.neighbours4([1, 1]).count(), 4);
+        assert_eq!(
+            grid.neighbours4([0, 0]).collect::<Vec<_>>(),
+            vec![[0, 1], [1, 0]]
+        );
+        assert_eq!(
+            grid.neighbours8([0, 0]).collect::<Vec<_>>(),
+            vec![[0, 1], [1, 1], [1, 0]]
+        );
+        assert_eq!(grid.neighbours8([1, 1]).count(), 8);
+
+        assert_eq!(grid.step([0, 0], NEIGHBOURS_4[0]), None);
+        assert_eq!(grid.step([0, 0], NEIGHBOURS_4[1]), Some([0, 1]));
+        assert_eq!(grid.step_wrapping([0, 0], NEIGHBOURS_4[0]), [2, 0]);
+        assert_eq!(grid.step_wrapping([2, 2], [1, 1]), [0, 0]);
+    }
+
+    #[test]
+    fn looks_up_signed_coordinates() {
+        let grid: Grid<Tile> = MAP.parse().unwrap();
+        assert_eq!(grid.get_signed([1, 1]), Some(&Tile::Start));
+        assert_eq!(grid.get_signed([-1, 1]), None);
+        assert_eq!(grid.get_signed([1, 3]), None);
+        assert!(grid.contains([2, 2]) && !grid.contains([3, 0]));
+        assert_eq!(grid.get_wrapping([-2, 4]), &Tile::Start);
+        assert_eq!(grid.get_wrapping([3, -1]), &Tile::Rock);
+    }
+}
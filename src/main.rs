@@ -1,55 +1,169 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, check_input, download, examples, read, run_part, scaffold, solve, verify,
+};
 use args::{parse, AppArguments};
 
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
+    use std::path::PathBuf;
     use std::process;
 
-    use advent_of_code::Day;
+    use std::time::Duration;
+
+    use advent_of_code::{
+        template::{
+            commands::all::AllOptions,
+            history::BaselineOptions,
+            input::InputSource,
+            isolation::RUN_PART_COMMAND,
+            runner::{BenchOptions, RunOptions},
+        },
+        DaySet, PuzzleId, Year,
+    };
 
     pub enum AppArguments {
+        CheckInput,
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
+        },
+        Examples {
+            puzzle: PuzzleId,
+            force: bool,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
+            template: Option<String>,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
-            time: bool,
-            submit: Option<u8>,
+            watch: bool,
+            options: RunOptions,
         },
         All {
-            release: bool,
-            time: bool,
+            options: AllOptions,
+        },
+        Verify {
+            puzzles: Vec<PuzzleId>,
+        },
+        RunPart {
+            puzzle: PuzzleId,
+            part: u8,
+            options: RunOptions,
         },
     }
 
+    fn parse_baseline(
+        args: &mut pico_args::Arguments,
+    ) -> Result<BaselineOptions, pico_args::Error> {
+        let defaults = BaselineOptions::default();
+        Ok(BaselineOptions {
+            save: args.contains("--save-baseline"),
+            compare: args.contains("--compare"),
+            threshold_percent: args
+                .opt_value_from_str("--threshold")?
+                .unwrap_or(defaults.threshold_percent),
+        })
+    }
+
+    fn parse_bench(args: &mut pico_args::Arguments) -> Result<BenchOptions, pico_args::Error> {
+        let defaults = BenchOptions::default();
+        Ok(BenchOptions {
+            warmup: args
+                .opt_value_from_str("--warmup")?
+                .unwrap_or(defaults.warmup),
+            budget: args
+                .opt_value_from_str("--budget")?
+                .map_or(defaults.budget, Duration::from_millis),
+        })
+    }
+
+    fn parse_input(args: &mut pico_args::Arguments) -> Result<InputSource, pico_args::Error> {
+        let input: Option<String> = args.opt_value_from_str("--input")?;
+        Ok(match input.as_deref() {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None if args.contains("--example") => InputSource::Example(args.opt_free_from_str()?),
+            None => InputSource::Puzzle,
+        })
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
+                options: AllOptions {
+                    year: args.opt_value_from_str("--year")?.unwrap_or(Year::home()),
+                    is_release: args.contains("--release"),
+                    is_timed: args.contains("--time"),
+                    is_fresh: args.contains("--fresh"),
+                    count_allocs: args.contains("--alloc"),
+                    days: args.opt_value_from_str("--days")?.unwrap_or(DaySet::all()),
+                    jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                    timeout: args
+                        .opt_value_from_str("--timeout")?
+                        .filter(|&seconds| seconds > 0)
+                        .map(Duration::from_secs),
+                    report: args.contains("--report"),
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    bench: parse_bench(&mut args)?,
+                    baseline: parse_baseline(&mut args)?,
+                },
             },
+            Some("check-input") => AppArguments::CheckInput,
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: args.free_from_str()?,
+            },
+            Some("examples") => AppArguments::Examples {
+                force: args.contains("--force"),
+                puzzle: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                template: args.opt_value_from_str("--template")?,
+                puzzle: args.free_from_str()?,
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                puzzle: args.free_from_str()?,
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                watch: args.contains("--watch"),
+                options: RunOptions {
+                    submit: args.opt_value_from_str("--submit")?,
+                    force_submit: args.contains("--force"),
+                    is_timed: args.contains("--time"),
+                    count_allocs: args.contains("--alloc"),
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    bench: parse_bench(&mut args)?,
+                    // parsed last, the trial of `--example` is the only free argument left.
+                    input: parse_input(&mut args)?,
+                    ..RunOptions::default()
+                },
+            },
+            Some("verify") => {
+                let mut puzzles = vec![];
+                while let Some(puzzle) = args.opt_free_from_str()? {
+                    puzzles.push(puzzle);
+                }
+                AppArguments::Verify { puzzles }
+            }
+            Some(RUN_PART_COMMAND) => AppArguments::RunPart {
+                puzzle: args.free_from_str()?,
+                part: args.free_from_str()?,
+                options: RunOptions {
+                    is_timed: args.contains("--time"),
+                    count_allocs: args.contains("--alloc"),
+                    bench: parse_bench(&mut args)?,
+                    ..RunOptions::default()
+                },
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -71,22 +185,40 @@ mod args {
 }
 
 fn main() {
+    let registry = solutions::registry();
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All { options } => all::handle(&registry, &options),
+            AppArguments::CheckInput => check_input::handle(),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Examples { puzzle, force } => examples::handle(puzzle, force),
+            AppArguments::Scaffold { puzzle, template } => {
+                scaffold::handle(puzzle, template.as_deref());
+            }
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
-                time,
-                submit,
-            } => solve::handle(day, release, time, submit),
+                watch,
+                options,
+            } => {
+                if watch {
+                    solve::watch(puzzle, release, &options);
+                } else {
+                    solve::handle(&registry, puzzle, release, &options);
+                }
+            }
+            AppArguments::Verify { puzzles } => verify::handle(&registry, &puzzles),
+            AppArguments::RunPart {
+                puzzle,
+                part,
+                options,
+            } => run_part::handle(&registry, puzzle, part, &options),
         },
     };
 }
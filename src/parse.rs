@@ -0,0 +1,207 @@
+/// Parsers for the recurring pieces of puzzle inputs, built with nom.
+///
+/// A day combines them into a parser of its whole input and turns that into [`FromStr`](std::str::FromStr)
+/// with [`from_str!`](crate::from_str), or calls [`complete`] directly.
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, line_ending, multispace0, one_of, space0, space1},
+    combinator::{eof, map_res, opt, recognize},
+    error::Error,
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
+    Finish, IResult, Parser,
+};
+
+use crate::template::SolutionError;
+
+/// Parses a number without a sign, e.g. `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Parses a number with an optional sign, e.g. `-42` or `+42`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// Parses numbers separated by spaces, e.g. `0 3 -6 9`.
+pub fn numbers<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(space1, signed)(input)
+}
+
+/// Parses one or more items with `parser`, one per line.
+pub fn lines<'a, O>(
+    parser: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(line_ending, parser)
+}
+
+/// Parses one or more sections with `parser`, separated by a blank line.
+pub fn sections<'a, O>(
+    parser: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(line_ending, line_ending), parser)
+}
+
+/// Parses the values after a fixed label and a colon, e.g. `seeds: 79 14 55` with `labelled("seeds", numbers)`.
+pub fn labelled<'a, O>(
+    label: &'static str,
+    parser: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    preceded(tuple((tag(label), char(':'), space0)), parser)
+}
+
+/// Parses a key and its values, separated by a colon, e.g. `Card 1: 41 48` with `key_value(card, numbers)`.
+pub fn key_value<'a, K, V>(
+    key: impl Parser<&'a str, K, Error<&'a str>>,
+    values: impl Parser<&'a str, V, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V)> {
+    separated_pair(key, pair(char(':'), space0), values)
+}
+
+/// Runs `parser` on the whole `input`. Input that is left over, except for trailing whitespace,
+/// fails like any other parse error, with a [`SolutionError::Parse`] pointing at it.
+pub fn complete<'a, O>(
+    parser: impl Parser<&'a str, O, Error<&'a str>>,
+    input: &'a str,
+) -> Result<O, SolutionError> {
+    let (_, output) = terminated(parser, pair(multispace0, eof))(input)
+        .finish()
+        .map_err(|e| SolutionError::parse(input, e))?;
+    Ok(output)
+}
+
+/// Implements [`FromStr`](std::str::FromStr) for a type with a nom parser of the whole input,
+/// failing with a [`SolutionError`](crate::template::SolutionError) if it does not parse, see [`complete`].
+///
+/// ```
+/// # use advent_of_code::template::SolutionError;
+/// use advent_of_code::parse::{labelled, numbers};
+/// use nom::IResult;
+///
+/// struct Seeds(Vec<u64>);
+///
+/// fn parse_seeds(input: &str) -> IResult<&str, Seeds> {
+///     let (i, seeds) = labelled("seeds", numbers)(input)?;
+///     Ok((i, Seeds(seeds)))
+/// }
+///
+/// advent_of_code::from_str!(Seeds, parse_seeds);
+///
+/// fn main() -> Result<(), SolutionError> {
+///     let seeds: Seeds = "seeds: 79 14 55\n".parse()?;
+///     assert_eq!(seeds.0, vec![79, 14, 55]);
+///     assert!("seeds: 79 x".parse::<Seeds>().is_err());
+///     Ok(())
+/// }
+/// ```
+#[macro_export]
+macro_rules! from_str {
+    ($type:ty, $parser:expr) => {
+        impl ::std::str::FromStr for $type {
+            type Err = $crate::template::SolutionError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $crate::parse::complete($parser, s)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use nom::{bytes::complete::tag, error::ErrorKind, sequence::preceded, IResult};
+
+    use super::{complete, key_value, labelled, lines, numbers, sections, signed, unsigned};
+    use crate::template::SolutionError;
+
+    #[derive(Debug, PartialEq)]
+    struct Card {
+        id: u32,
+        numbers: Vec<u32>,
+    }
+
+    fn parse_card(input: &str) -> IResult<&str, Card> {
+        let (i, (id, numbers)) = key_value(preceded(tag("Card "), unsigned), numbers)(input)?;
+        Ok((i, Card { id, numbers }))
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Cards(Vec<Card>);
+
+    fn parse_cards(input: &str) -> IResult<&str, Cards> {
+        let (i, cards) = lines(parse_card)(input)?;
+        Ok((i, Cards(cards)))
+    }
+
+    crate::from_str!(Cards, parse_cards);
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(unsigned::<u32>("42 x"), Ok((" x", 42)));
+        assert_eq!(signed::<i64>("-42"), Ok(("", -42)));
+        assert_eq!(signed::<i64>("+42"), Ok(("", 42)));
+        assert!(unsigned::<u32>("-42").is_err());
+        assert!(unsigned::<u8>("256").is_err());
+        assert_eq!(numbers::<i32>("0 3  -6 9"), Ok(("", vec![0, 3, -6, 9])));
+    }
+
+    #[test]
+    fn parses_lines_and_sections() {
+        assert_eq!(
+            lines(numbers::<u32>)("1 2\n3\n\n4"),
+            Ok(("\n\n4", vec![vec![1, 2], vec![3]]))
+        );
+        assert_eq!(
+            sections(lines(unsigned::<u32>))("1\n2\n\n3\r\n\r\n4\n"),
+            Ok(("\n", vec![vec![1, 2], vec![3], vec![4]]))
+        );
+    }
+
+    #[test]
+    fn parses_labelled_values() {
+        assert_eq!(
+            labelled("seeds", numbers::<u64>)("seeds: 79 14"),
+            Ok(("", vec![79, 14]))
+        );
+        assert_eq!(
+            parse_card("Card 3: 1 21"),
+            Ok((
+                "",
+                Card {
+                    id: 3,
+                    numbers: vec![1, 21]
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_trailing_input() {
+        assert_eq!(complete(numbers::<u32>, "1 2 3\n\n"), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            complete(numbers::<u32>, "1 2 x"),
+            Err(SolutionError::Parse {
+                kind: ErrorKind::Eof,
+                line: 1,
+                column: 5,
+                source_line: "1 2 x".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn implements_from_str() {
+        let cards: Cards = "Card 1: 41 48\nCard 2: 13\n".parse().unwrap();
+        assert_eq!(cards.0.len(), 2);
+        assert_eq!(cards.0[1].numbers, vec![13]);
+
+        let error = "Card 1: 41 48\nCard 2 13\n".parse::<Cards>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "failed to parse the input at line 2, column 1 (Eof)"
+        );
+    }
+}
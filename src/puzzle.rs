@@ -0,0 +1,134 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::{Day, Year};
+
+/// Identifies a puzzle by its year and day.
+///
+/// Puzzles of the [home year](Year::home) live at the top level, e.g. `src/bin/12.rs` and `data/inputs/12.txt`.
+/// Puzzles of other years have the year in their paths, e.g. `src/bin/2022_12.rs` and `data/2022/inputs/12.txt`.
+///
+/// # Display
+/// This value displays as `year/day`, which is also the format it parses from.
+///
+/// ```
+/// # use advent_of_code::{PuzzleId, Day, Year};
+/// let puzzle: PuzzleId = "2022/12".parse().unwrap();
+/// assert_eq!(puzzle, PuzzleId::new(Year::new(2022).unwrap(), Day::new(12).unwrap()));
+/// assert_eq!(puzzle.to_string(), "2022/12")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Whether the puzzle belongs to the year configured with `AOC_YEAR`.
+    pub fn is_home(self) -> bool {
+        self.year == Year::home()
+    }
+
+    /// The name of the binary that solves the puzzle, e.g. `12` or `2022_12`.
+    pub fn bin_name(self) -> String {
+        if self.is_home() {
+            self.day.to_string()
+        } else {
+            format!("{}_{}", self.year, self.day)
+        }
+    }
+
+    /// The folder below `data` that holds the puzzle's files of a kind, e.g. `inputs` or `2022/inputs`.
+    pub fn data_folder(self, folder: &str) -> PathBuf {
+        if self.is_home() {
+            PathBuf::from(folder)
+        } else {
+            PathBuf::from(self.year.to_string()).join(folder)
+        }
+    }
+}
+
+/// Days without a year belong to the home year.
+impl From<Day> for PuzzleId {
+    fn from(day: Day) -> Self {
+        Self::new(Year::home(), day)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('/') {
+            Some((year, day)) => Ok(Self::new(
+                year.parse().map_err(|_| PuzzleIdFromStrError)?,
+                day.parse().map_err(|_| PuzzleIdFromStrError)?,
+            )),
+            None => Ok(s.parse::<Day>().map_err(|_| PuzzleIdFromStrError)?.into()),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError;
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a day number between 1 and 25, optionally prefixed with a year (e.g. 2022/12)")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::PuzzleId;
+    use crate::{day, year, Year};
+
+    #[test]
+    fn parses_puzzle_ids() {
+        assert_eq!(
+            "2022/12".parse::<PuzzleId>().unwrap(),
+            PuzzleId::new(year!(2022), day!(12))
+        );
+        assert_eq!(
+            "7".parse::<PuzzleId>().unwrap(),
+            PuzzleId::new(Year::home(), day!(7))
+        );
+        assert!("2022/26".parse::<PuzzleId>().is_err());
+        assert!("2014/1".parse::<PuzzleId>().is_err());
+        assert!("2022/".parse::<PuzzleId>().is_err());
+    }
+
+    #[test]
+    fn builds_paths() {
+        let home = PuzzleId::from(day!(7));
+        assert_eq!(home.bin_name(), "07");
+        assert_eq!(home.data_folder("inputs"), PathBuf::from("inputs"));
+
+        let other = PuzzleId::new(year!(2015), day!(7));
+        assert_eq!(other.bin_name(), "2015_07");
+        assert_eq!(other.data_folder("inputs"), PathBuf::from("2015/inputs"));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,285 @@
+/// Module with a global allocator that counts the allocations of a part, opt in with `--alloc`.
+/// The allocator is only installed with the `count_allocs` feature, so other builds keep the system allocator.
+/// Counting is switched off unless a part runs through [`count`], which costs an atomic load per allocation.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Display,
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Wraps the system allocator and counts the allocations of the thread that runs [`count`].
+pub struct CountingAllocator;
+
+#[cfg(feature = "count_allocs")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Whether the counting allocator is installed, i.e. the crate was built with the `count_allocs` feature.
+pub const IS_AVAILABLE: bool = cfg!(feature = "count_allocs");
+
+/// Exits with an error if `--alloc` was passed to a build without the counting allocator.
+pub fn ensure_available() {
+    if !IS_AVAILABLE {
+        eprintln!(
+            "Counting allocations requires the `count_allocs` feature, e.g. `cargo run --release --features count_allocs -- all --release --time --alloc`."
+        );
+        process::exit(1);
+    }
+}
+
+/// Number of threads that are counting, so allocations skip the thread-local counters while none is.
+static COUNTING_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// The counters of a thread. Solutions are single-threaded, and counting per thread
+/// keeps parts that run concurrently, e.g. in tests, from counting each other's allocations.
+#[derive(Clone, Copy)]
+struct Counters {
+    is_counting: bool,
+    count: u64,
+    bytes: u64,
+    // relative to the start of counting, memory allocated before and freed during the run makes this negative.
+    live: isize,
+    peak: isize,
+}
+
+impl Counters {
+    const IDLE: Self = Self {
+        is_counting: false,
+        count: 0,
+        bytes: 0,
+        live: 0,
+        peak: 0,
+    };
+}
+
+thread_local! {
+    // const-initialized without a destructor, so it neither allocates nor goes away before the thread does.
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters::IDLE) };
+}
+
+fn update(func: impl FnOnce(&mut Counters)) {
+    if COUNTING_THREADS.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+
+    let _ = COUNTERS.try_with(|cell| {
+        let mut counters = cell.get();
+        if counters.is_counting {
+            func(&mut counters);
+            cell.set(counters);
+        }
+    });
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn record_alloc(size: usize) {
+    update(|counters| {
+        counters.count += 1;
+        counters.bytes += size as u64;
+        counters.live += size as isize;
+        counters.peak = counters.peak.max(counters.live);
+    });
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn record_dealloc(size: usize) {
+    update(|counters| counters.live -= size as isize);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    // a reallocation counts as a new allocation of `new_size` bytes.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// The allocations of a single run of a part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub count: u64,
+    /// Total bytes allocated.
+    pub bytes: u64,
+    /// Highest number of bytes that were allocated at the same time.
+    pub peak_bytes: u64,
+}
+
+impl AllocStats {
+    /// A compact form for tables, the count and the peak, e.g. `1.2k · 4.0 MiB`.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!(
+            "{} · {}",
+            format_count(self.count),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs · {} allocated · {} peak",
+            format_count(self.count),
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Resets the counters on creation and stops counting when dropped, also if the part panics.
+struct CountingGuard;
+
+impl CountingGuard {
+    fn start() -> Self {
+        COUNTING_THREADS.fetch_add(1, Ordering::Relaxed);
+        COUNTERS.with(|cell| {
+            cell.set(Counters {
+                is_counting: true,
+                ..Counters::IDLE
+            });
+        });
+        Self
+    }
+}
+
+impl Drop for CountingGuard {
+    fn drop(&mut self) {
+        COUNTERS.with(|cell| {
+            cell.set(Counters {
+                is_counting: false,
+                ..cell.get()
+            });
+        });
+        COUNTING_THREADS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Runs `func` and counts its allocations. Only allocations of the current thread are counted.
+pub fn count<T>(func: impl FnOnce() -> T) -> (T, AllocStats) {
+    let guard = CountingGuard::start();
+    let result = func();
+    drop(guard);
+
+    let counters = COUNTERS.with(Cell::get);
+    let stats = AllocStats {
+        count: counters.count,
+        bytes: counters.bytes,
+        peak_bytes: u64::try_from(counters.peak).unwrap_or(0),
+    };
+    (result, stats)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_count(count: u64) -> String {
+    match count {
+        0..=999 => count.to_string(),
+        1_000..=999_999 => format!("{:.1}k", count as f64 / 1e3),
+        _ => format!("{:.1}M", count as f64 / 1e6),
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    #[cfg(feature = "count_allocs")]
+    use std::hint::black_box;
+
+    #[cfg(feature = "count_allocs")]
+    use super::count;
+    use super::{format_bytes, format_count, AllocStats};
+
+    #[cfg(feature = "count_allocs")]
+    #[test]
+    fn counts_allocations() {
+        let (sum, stats) = count(|| {
+            let a: Vec<u64> = black_box(vec![1; 1000]);
+            let b: Vec<u64> = black_box(vec![2; 1000]);
+            let sum = a.iter().sum::<u64>() + b.iter().sum::<u64>();
+            drop(a);
+            drop(b);
+            let c: Vec<u64> = black_box(vec![3; 10]);
+            sum + c[0]
+        });
+
+        assert_eq!(sum, 3003);
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.bytes, 16080);
+        assert_eq!(stats.peak_bytes, 16000);
+    }
+
+    #[cfg(feature = "count_allocs")]
+    #[test]
+    fn counts_runs_without_allocations() {
+        let (_, stats) = count(|| black_box(1 + 1));
+        assert_eq!(stats, AllocStats::default());
+    }
+
+    #[test]
+    fn formats_stats() {
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1_250), "1.2k");
+        assert_eq!(format_count(3_400_000), "3.4M");
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(4096), "4.0 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 + 1), "5.0 MiB");
+
+        let stats = AllocStats {
+            count: 1_200,
+            bytes: 2048,
+            peak_bytes: 1024,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "1.2k allocs · 2.0 KiB allocated · 1.0 KiB peak"
+        );
+        assert_eq!(stats.summary(), "1.2k · 1.0 KiB");
+    }
+}
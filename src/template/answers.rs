@@ -0,0 +1,107 @@
+/// Module that stores the answers that were accepted by the puzzle service, one file per puzzle.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::get_data_path;
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// The accepted answers of a single day.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_one = Some(answer.into()),
+            2 => self.part_two = Some(answer.into()),
+            _ => {}
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path_for_answers(puzzle: PuzzleId) -> PathBuf {
+    get_data_path("answers", puzzle, &format!("{}.toml", puzzle.day))
+}
+
+/// Reads the accepted answers for a puzzle. Puzzles without an answers file have no answers.
+pub fn read(puzzle: PuzzleId) -> Result<Answers, Error> {
+    read_from(&get_path_for_answers(puzzle))
+}
+
+/// Reads accepted answers from an arbitrary answers file.
+pub fn read_from(path: &Path) -> Result<Answers, Error> {
+    if !path.exists() {
+        return Ok(Answers::default());
+    }
+    let content = fs::read_to_string(path)?;
+    toml::from_str(&content).map_err(|e| Error::Parser(e.to_string()))
+}
+
+/// Stores `answer` as the accepted answer for a part.
+pub fn record(puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), Error> {
+    let mut answers = read(puzzle)?;
+    answers.set(part, answer);
+
+    let path = get_path_for_answers(puzzle);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let content = toml::to_string(&answers).map_err(|e| Error::Parser(e.to_string()))?;
+    fs::write(path, content)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answers;
+
+    #[test]
+    fn serializes_answers() {
+        let mut answers = Answers::default();
+        answers.set(1, "12345");
+        assert_eq!(toml::to_string(&answers).unwrap(), "part_one = \"12345\"\n");
+
+        answers.set(2, "678");
+        let parsed: Answers = toml::from_str(&toml::to_string(&answers).unwrap()).unwrap();
+        assert_eq!(parsed.get(1), Some("12345"));
+        assert_eq!(parsed.get(2), Some("678"));
+    }
+
+    #[test]
+    fn parses_partial_answers() {
+        let answers: Answers = toml::from_str("part_two = \"42\"").unwrap();
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some("42"));
+    }
+}
@@ -1,10 +1,14 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
+    env,
+    ffi::OsStr,
     fmt::Display,
-    process::{Command, Output, Stdio},
+    fs,
+    process::{self, Command, Output, Stdio},
 };
 
-use crate::Day;
+use crate::template::submission::Verdict;
+use crate::PuzzleId;
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -12,6 +16,7 @@ pub enum AocCommandError {
     CommandNotCallable,
     BadExitStatus(Output),
     IoError,
+    UnknownResponse(String),
 }
 
 impl Display for AocCommandError {
@@ -22,100 +27,125 @@ impl Display for AocCommandError {
             AocCommandError::BadExitStatus(_) => {
                 write!(f, "aoc-cli exited with a non-zero status.")
             }
-            AocCommandError::IoError => write!(f, "could not write output files to file system."),
+            AocCommandError::IoError => write!(f, "could not read output files from file system."),
+            AocCommandError::UnknownResponse(response) => {
+                write!(f, "could not interpret the response: {}", response.trim())
+            }
         }
     }
 }
 
+/// The aoc-cli executable, looked up in the `PATH`.
+const COMMAND: &str = "aoc";
+
 pub fn check() -> Result<(), AocCommandError> {
-    Command::new("aoc")
+    Command::new(COMMAND)
         .arg("-V")
         .output()
         .map_err(|_| AocCommandError::CommandNotFound)?;
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+/// Downloads the input of a puzzle and returns its contents.
+pub fn download_input(puzzle: PuzzleId) -> Result<String, AocCommandError> {
+    download_to_temp_file(puzzle, "--input-only", "--input-file", "txt")
+}
 
-    let args = build_args(
-        "read",
-        &[
-            "--description-only".into(),
-            "--puzzle-file".into(),
-            puzzle_path,
-        ],
-        day,
+/// Downloads the description of a puzzle and returns it as markdown.
+pub fn download_puzzle(puzzle: PuzzleId) -> Result<String, AocCommandError> {
+    download_to_temp_file(puzzle, "--puzzle-only", "--puzzle-file", "md")
+}
+
+/// Submits an answer and parses the response into a [`Verdict`].
+pub fn submit(puzzle: PuzzleId, part: u8, result: &str) -> Result<Verdict, AocCommandError> {
+    submit_with(COMMAND.as_ref(), puzzle, part, result)
+}
+
+/// Like [`submit`], but calls the aoc-cli executable at `command`.
+fn submit_with(
+    command: &OsStr,
+    puzzle: PuzzleId,
+    part: u8,
+    result: &str,
+) -> Result<Verdict, AocCommandError> {
+    // workaround: the argument order is inverted for submit.
+    let mut args = build_args("submit", &[], puzzle);
+    args.push(part.to_string());
+    args.push(result.to_string());
+
+    let output = match call_aoc_cli_with_output(command, &args, Stdio::piped(), Stdio::piped()) {
+        // aoc-cli reports some rejected answers with a non-zero status.
+        Ok(output) | Err(AocCommandError::BadExitStatus(output)) => output,
+        Err(e) => return Err(e),
+    };
+
+    let response = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
     );
 
-    call_aoc_cli(&args)
+    Verdict::from_response(&response).ok_or(AocCommandError::UnknownResponse(response))
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+fn download_to_temp_file(
+    puzzle: PuzzleId,
+    only_flag: &str,
+    file_flag: &str,
+    extension: &str,
+) -> Result<String, AocCommandError> {
+    let path = env::temp_dir().join(format!(
+        "aoc-{}-{}.{extension}",
+        process::id(),
+        puzzle.bin_name()
+    ));
 
     let args = build_args(
         "download",
         &[
             "--overwrite".into(),
-            "--input-file".into(),
-            input_path.to_string(),
-            "--puzzle-file".into(),
-            puzzle_path.to_string(),
+            only_flag.into(),
+            file_flag.into(),
+            path.to_string_lossy().to_string(),
         ],
-        day,
+        puzzle,
     );
 
-    let output = call_aoc_cli(&args)?;
-    println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-    Ok(output)
+    call_aoc_cli(&args)?;
+    let contents = fs::read_to_string(&path).map_err(|_| AocCommandError::IoError)?;
+    let _ = fs::remove_file(&path);
+    Ok(contents)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
-    // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
-    args.push(part.to_string());
-    args.push(result.to_string());
-    call_aoc_cli(&args)
-}
-
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
-}
-
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
-}
-
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
-}
-
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], puzzle: PuzzleId) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        puzzle.year.to_string(),
+        "--day".into(),
+        puzzle.day.to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
 
 fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+    call_aoc_cli_with_output(COMMAND.as_ref(), args, Stdio::inherit(), Stdio::inherit())
+}
+
+fn call_aoc_cli_with_output(
+    command: &OsStr,
+    args: &[String],
+    stdout: Stdio,
+    stderr: Stdio,
+) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
-    let output = Command::new("aoc")
+    let output = Command::new(command)
         .args(args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
+        .stdout(stdout)
+        .stderr(stderr)
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
 
@@ -125,3 +155,53 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+#[cfg(all(feature = "test_lib", unix))]
+mod tests {
+    use std::{fs, os::unix::fs::PermissionsExt};
+
+    use super::submit_with;
+    use crate::day;
+    use crate::template::submission::Verdict;
+    use crate::template::{fixture_dir, write_fixture};
+
+    /// Prints a canned response depending on the submitted answer, like aoc-cli would.
+    static FAKE_AOC: &str = r#"#!/bin/sh
+for answer; do :; done
+echo "[INFO  aoc] 🎄 aoc-cli - Advent of Code command-line tool" >&2
+case "$answer" in
+    42) echo "That's the right answer! You are one gold star closer to saving Christmas." ;;
+    100) echo "That's not the right answer; your answer is too high." ;;
+    1) echo "That's not the right answer; your answer is too low." ;;
+    wait) echo "You gave an answer too recently. You have 1m 3s left to wait." ;;
+    done) echo "You don't seem to be solving the right level. Did you already complete it?"; exit 1 ;;
+    *) echo "Something went wrong." ;;
+esac
+"#;
+
+    #[test]
+    fn parses_submit_output() {
+        let script = fixture_dir("fake_cli").join("aoc");
+        write_fixture(&script, FAKE_AOC);
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let cases = [
+            ("42", Verdict::Correct),
+            ("100", Verdict::TooHigh),
+            ("1", Verdict::TooLow),
+            (
+                "wait",
+                Verdict::RateLimited(std::time::Duration::from_secs(63)),
+            ),
+            ("done", Verdict::WrongLevel),
+        ];
+        for (answer, verdict) in cases {
+            assert_eq!(
+                submit_with(script.as_os_str(), day!(1).into(), 1, answer).unwrap(),
+                verdict,
+                "{answer}"
+            );
+        }
+        assert!(submit_with(script.as_os_str(), day!(1).into(), 1, "7").is_err());
+    }
+}
@@ -0,0 +1,327 @@
+/// Module that caches the results of `all`, so days whose sources and input did not change are not run again.
+/// Every day is stored as a single line of JSON, keyed by a hash of everything its results depend on.
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::protocol::PartRecord;
+use crate::template::runner::RunOptions;
+use crate::{Day, PuzzleId, Year};
+
+static CACHE_PATH: &str = "data/cache/results.jsonl";
+
+/// The files below the crate root that configure how the solutions are built.
+static MANIFESTS: [&str; 2] = ["Cargo.toml", "Cargo.lock"];
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// The cached results of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub year: Year,
+    pub day: Day,
+    /// The hash of the sources, input and options the records were computed with, see [`key`].
+    pub key: String,
+    pub records: Vec<PartRecord>,
+}
+
+/// The cached results of every puzzle.
+#[derive(Debug, Default)]
+pub struct Cache {
+    entries: Vec<CacheEntry>,
+}
+
+impl Cache {
+    /// Reads the cache from `path`. A cache that does not exist is empty.
+    pub fn read_from(path: &Path) -> Result<Self, Error> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let entries = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(|e| Error::Parser(e.to_string())))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { entries })
+    }
+
+    /// Reads the cache of the current project.
+    pub fn read() -> Result<Self, Error> {
+        Self::read_from(Path::new(CACHE_PATH))
+    }
+
+    pub fn write_to(&self, path: &Path) -> Result<(), Error> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+
+        for entry in &self.entries {
+            let line = serde_json::to_string(entry).map_err(|e| Error::Parser(e.to_string()))?;
+            writeln!(file, "{line}")?;
+        }
+
+        Ok(())
+    }
+
+    pub fn write(&self) -> Result<(), Error> {
+        self.write_to(Path::new(CACHE_PATH))
+    }
+
+    /// Returns the cached records of a puzzle if they were computed with the same `key`.
+    #[must_use]
+    pub fn get(&self, puzzle: PuzzleId, key: &str) -> Option<&[PartRecord]> {
+        self.entries
+            .iter()
+            .find(|entry| entry.year == puzzle.year && entry.day == puzzle.day && entry.key == key)
+            .map(|entry| entry.records.as_slice())
+    }
+
+    /// Stores the records of a puzzle, replacing any previous results.
+    pub fn insert(&mut self, puzzle: PuzzleId, key: String, records: Vec<PartRecord>) {
+        self.entries
+            .retain(|entry| entry.year != puzzle.year || entry.day != puzzle.day);
+        self.entries.push(CacheEntry {
+            year: puzzle.year,
+            day: puzzle.day,
+            key,
+            records,
+        });
+        self.entries.sort_by_key(|entry| (entry.year, entry.day));
+    }
+}
+
+/// Computes the cache key of a puzzle below the crate `root`: a hash of its binary, the library sources,
+/// the manifest and lockfile, its `input` and the options that change its results or timings.
+pub fn key(root: &Path, puzzle: PuzzleId, input: &str, options: &RunOptions) -> io::Result<String> {
+    let mut hasher = Fnv1a::default();
+
+    let src = root.join("src");
+    let mut files = vec![src.join("bin").join(format!("{}.rs", puzzle.bin_name()))];
+    files.extend(library_sources(root));
+
+    for file in files {
+        hasher.write_str(&file.strip_prefix(root).unwrap_or(&file).to_string_lossy());
+        hasher.write(&fs::read(&file)?);
+    }
+
+    // dependency upgrades and profile settings such as `opt-level` or `lto` change the build.
+    for manifest in MANIFESTS {
+        match fs::read(root.join(manifest)) {
+            Ok(contents) => {
+                hasher.write_str(manifest);
+                hasher.write(&contents);
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    hasher.write_str(input);
+
+    // debug builds are much slower, timed runs carry benchmark statistics and counted runs allocation counts.
+    hasher.write_str(&format!("debug={}", cfg!(debug_assertions)));
+    if options.is_timed {
+        hasher.write_str(&format!("timed={:?}", options.bench));
+    }
+    if options.count_allocs {
+        hasher.write_str("alloc");
+    }
+
+    Ok(format!("{:016x}", hasher.0))
+}
+
+/// Lists the sources of the library below the crate `root`, every file in `src` except the solutions and `main.rs`.
+#[must_use]
+pub fn library_sources(root: &Path) -> Vec<PathBuf> {
+    let src = root.join("src");
+    let mut files: Vec<PathBuf> = find_files_recursive(&src)
+        .into_iter()
+        .filter(|path| !path.starts_with(src.join("bin")) && *path != src.join("main.rs"))
+        .collect();
+    files.sort();
+    files
+}
+
+fn find_files_recursive(dir: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    if let Ok(entries) = dir.read_dir() {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                files.extend(find_files_recursive(&path));
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files
+}
+
+/// The 64-bit FNV-1a hash, which unlike the hasher of the standard library is stable across Rust versions.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        // prefixing the length keeps `"ab" + "c"` and `"a" + "bc"` apart.
+        for byte in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_str(&mut self, s: &str) {
+        self.write(s.as_bytes());
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::{key, library_sources, Cache};
+    use crate::template::protocol::PartRecord;
+    use crate::template::runner::RunOptions;
+    use crate::template::{fixture_dir, write_fixture};
+    use crate::{day, PuzzleId};
+
+    #[test]
+    fn lists_library_sources() {
+        let root = fixture_dir("library_sources");
+        for file in [
+            "src/main.rs",
+            "src/lib.rs",
+            "src/bin/03.rs",
+            "src/template/mod.rs",
+        ] {
+            write_fixture(root.join(file), "");
+        }
+
+        assert_eq!(
+            library_sources(&root),
+            vec![root.join("src/lib.rs"), root.join("src/template/mod.rs")]
+        );
+    }
+
+    #[test]
+    fn changes_key_with_sources_input_and_options() {
+        let root = fixture_dir("cache_keys");
+        write_fixture(root.join("src/lib.rs"), "mod day;");
+        write_fixture(root.join("src/bin/03.rs"), "fn main() {}");
+        write_fixture(root.join("src/bin/04.rs"), "fn main() {}");
+
+        let puzzle = PuzzleId::from(day!(3));
+        let options = RunOptions::default();
+        let before = key(&root, puzzle, "1 2\n", &options).unwrap();
+        assert_eq!(before, key(&root, puzzle, "1 2\n", &options).unwrap());
+
+        write_fixture(root.join("src/bin/04.rs"), "fn main() { todo!() }");
+        assert_eq!(before, key(&root, puzzle, "1 2\n", &options).unwrap());
+
+        assert_ne!(before, key(&root, puzzle, "1 3\n", &options).unwrap());
+
+        let timed = RunOptions {
+            is_timed: true,
+            ..RunOptions::default()
+        };
+        assert_ne!(before, key(&root, puzzle, "1 2\n", &timed).unwrap());
+
+        let counted = RunOptions {
+            count_allocs: true,
+            ..RunOptions::default()
+        };
+        assert_ne!(before, key(&root, puzzle, "1 2\n", &counted).unwrap());
+
+        write_fixture(root.join("src/lib.rs"), "mod day;\nmod grid;");
+        assert_ne!(before, key(&root, puzzle, "1 2\n", &options).unwrap());
+
+        write_fixture(root.join("src/bin/03.rs"), "fn main() { todo!() }");
+        let changed = key(&root, puzzle, "1 2\n", &options).unwrap();
+        fs::remove_file(root.join("src/bin/03.rs")).unwrap();
+        assert!(key(&root, puzzle, "1 2\n", &options).is_err());
+        assert_ne!(before, changed);
+    }
+
+    #[test]
+    fn changes_key_with_manifest_and_lockfile() {
+        let root = fixture_dir("cache_manifests");
+        write_fixture(root.join("src/bin/03.rs"), "fn main() {}");
+
+        let puzzle = PuzzleId::from(day!(3));
+        let options = RunOptions::default();
+        let without_manifests = key(&root, puzzle, "", &options).unwrap();
+
+        write_fixture(root.join("Cargo.toml"), "[profile.release]\nlto = false");
+        let manifest = key(&root, puzzle, "", &options).unwrap();
+        assert_ne!(without_manifests, manifest);
+
+        write_fixture(root.join("Cargo.toml"), "[profile.release]\nlto = true");
+        let profile = key(&root, puzzle, "", &options).unwrap();
+        assert_ne!(manifest, profile);
+
+        write_fixture(
+            root.join("Cargo.lock"),
+            "[[package]]\nname = \"nom\"\nversion = \"7.1.3\"",
+        );
+        let lockfile = key(&root, puzzle, "", &options).unwrap();
+        assert_ne!(profile, lockfile);
+
+        write_fixture(
+            root.join("Cargo.lock"),
+            "[[package]]\nname = \"nom\"\nversion = \"8.0.0\"",
+        );
+        assert_ne!(lockfile, key(&root, puzzle, "", &options).unwrap());
+    }
+
+    #[test]
+    fn stores_results() {
+        let dir = fixture_dir("cache");
+        let path = dir.join("cache").join("results.jsonl");
+        let puzzle = PuzzleId::from(day!(3));
+        let records = vec![PartRecord {
+            answer: Some("42".into()),
+            ..PartRecord::unsolved(puzzle, 1)
+        }];
+
+        let mut cache = Cache::read_from(&path).unwrap();
+        assert_eq!(cache.get(puzzle, "abc"), None);
+
+        cache.insert(puzzle, "abc".into(), vec![]);
+        cache.insert(puzzle, "abc".into(), records.clone());
+        cache.write_to(&path).unwrap();
+
+        let cache = Cache::read_from(&path).unwrap();
+        assert_eq!(cache.get(puzzle, "abc"), Some(records.as_slice()));
+        assert_eq!(cache.get(puzzle, "def"), None);
+        assert_eq!(cache.get(day!(4).into(), "abc"), None);
+    }
+}
@@ -1,254 +1,638 @@
-use std::io;
+use std::{
+    collections::BTreeMap,
+    mem,
+    path::Path,
+    process, slice,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
 use crate::template::{
+    alloc,
+    cache::{self, Cache},
+    history::{self, BaselineOptions, HistoryEntry},
+    input::{self, InputError},
+    isolation,
+    protocol::{OutputFormat, PartRecord, PartStatus},
     readme_benchmarks::{self, Timings},
+    readme_stars,
+    registry::{Registry, Solution},
+    report::{self, REPORT_PATH},
+    runner::{print_record, BenchOptions, RunOptions},
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
-
-pub fn handle(is_release: bool, is_timed: bool) {
-    let mut timings: Vec<Timings> = vec![];
+use crate::{DaySet, PuzzleId, Year};
+
+/// Options of the `all` command.
+#[derive(Debug, Clone)]
+pub struct AllOptions {
+    pub year: Year,
+    pub is_release: bool,
+    pub is_timed: bool,
+    /// Runs every day, even if its results are cached.
+    pub is_fresh: bool,
+    /// Counts the allocations of every part and adds them to the README table.
+    pub count_allocs: bool,
+    /// The days to run, every day by default.
+    pub days: DaySet,
+    /// Number of days that run at the same time, timed runs are always sequential.
+    pub jobs: usize,
+    /// Runs every part in a child process that is killed after this long. Without one, parts run in-process.
+    pub timeout: Option<Duration>,
+    /// Writes an HTML report of the run, only done by timed runs in release mode.
+    pub report: bool,
+    pub format: OutputFormat,
+    pub bench: BenchOptions,
+    pub baseline: BaselineOptions,
+}
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+impl Default for AllOptions {
+    fn default() -> Self {
+        Self {
+            year: Year::home(),
+            is_release: false,
+            is_timed: false,
+            is_fresh: false,
+            count_allocs: false,
+            days: DaySet::all(),
+            jobs: 1,
+            timeout: None,
+            report: false,
+            format: OutputFormat::default(),
+            bench: BenchOptions::default(),
+            baseline: BaselineOptions::default(),
         }
+    }
+}
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+pub fn handle(registry: &Registry, all_options: &AllOptions) {
+    if all_options.count_allocs {
+        alloc::ensure_available();
+    }
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let format = all_options.format;
+    let is_text = format == OutputFormat::Text;
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
-        }
+    let options = RunOptions {
+        is_timed: all_options.is_timed,
+        count_allocs: all_options.count_allocs,
+        format,
+        bench: all_options.bench.clone(),
+        ..RunOptions::default()
+    };
+
+    let cache = Cache::read().unwrap_or_else(|e| {
+        eprintln!("Failed to read result cache, running every day: {e:?}");
+        Cache::default()
     });
 
-    if is_timed {
+    let puzzles: Vec<PuzzleId> = all_options
+        .days
+        .iter()
+        .map(|day| PuzzleId::new(all_options.year, day))
+        .collect();
+
+    let jobs: Vec<Job> = puzzles
+        .iter()
+        .map(|&puzzle| prepare(registry, puzzle, &options, &cache, all_options.is_fresh))
+        .collect();
+
+    let mut results = Results {
+        cache,
+        ..Results::default()
+    };
+
+    // parts that run concurrently slow each other down, so timed runs are always sequential.
+    if all_options.is_timed || all_options.jobs <= 1 {
+        if all_options.jobs > 1 {
+            eprintln!("Timed runs are sequential, ignoring --jobs.");
+        }
+        run_sequential(&puzzles, jobs, &options, all_options.timeout, &mut results);
+    } else {
+        run_parallel(
+            &puzzles,
+            jobs,
+            &options,
+            all_options.timeout,
+            all_options.jobs,
+            &mut results,
+        );
+    }
+
+    if results.cache_hits > 0 {
+        eprintln!(
+            "\nReused cached results of {} unchanged day(s), pass --fresh to run them again.",
+            results.cache_hits
+        );
+    }
+
+    if all_options.report && !(all_options.is_timed && all_options.is_release) {
+        eprintln!("Reports are only written by timed runs in release mode, run `cargo report`.");
+    }
+
+    if all_options.is_timed {
+        let timings = results.timings;
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if is_text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
+
+        if all_options.is_release {
+            match readme_stars::update(all_options.year) {
+                Ok(()) => eprintln!("Successfully updated README with stars."),
+                Err(_) => eprintln!("Failed to update README with stars."),
+            }
+
+            // the table lists every day, a run of some days would drop the others.
+            if all_options.days == DaySet::all() {
+                match readme_benchmarks::update(all_options.year, timings.clone(), total_millis) {
+                    Ok(()) => eprintln!("Successfully updated README with benchmarks."),
+                    Err(_) => {
+                        eprintln!("Failed to update readme with benchmarks.");
+                    }
+                }
+            } else {
+                eprintln!("Not updating README with benchmarks, as only some days were run.");
+            }
+
+            // cached timings are already part of the history.
+            if history::append(&HistoryEntry::from_records(&results.fresh_records)).is_err() {
+                eprintln!("Failed to append benchmarks to history.");
+            }
+
+            if all_options.report {
+                let history = history::read().unwrap_or_default();
+                let html =
+                    report::render(all_options.year, &timings, &results.all_records, &history);
+                match report::write(&html) {
+                    Ok(()) => eprintln!("Successfully wrote report to \"{REPORT_PATH}\"."),
+                    Err(_) => eprintln!("Failed to write report."),
+                }
+            }
 
-        if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
-                Err(_) => {
-                    eprintln!("Failed to update readme with benchmarks.");
+            let baseline = &all_options.baseline;
+            let entries = HistoryEntry::from_records(&results.all_records);
+
+            // compared before the baseline is replaced, but only failed once it is saved.
+            let has_regressed =
+                baseline.compare && !compare_to_baseline(&entries, baseline.threshold_percent);
+
+            if baseline.save {
+                match history::save_baseline(&entries) {
+                    Ok(()) => eprintln!("Successfully saved benchmarks as baseline."),
+                    Err(_) => eprintln!("Failed to save benchmarks as baseline."),
                 }
             }
+
+            if has_regressed {
+                process::exit(1);
+            }
         }
     }
 }
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
-}
+/// Runs the puzzles one after the other, their parts report themselves while they run.
+fn run_sequential(
+    puzzles: &[PuzzleId],
+    jobs: Vec<Job>,
+    options: &RunOptions,
+    timeout: Option<Duration>,
+    results: &mut Results,
+) {
+    for (i, (&puzzle, job)) in puzzles.iter().zip(jobs).enumerate() {
+        print_header(puzzle, i == 0, options.format);
+
+        let job = match job {
+            Job::Pending {
+                solution,
+                input,
+                key,
+            } => Job::Done {
+                records: run_solution(solution, &input, options, timeout),
+                key,
+            },
+            job => job,
+        };
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+        results.report(puzzle, job, options.format);
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// Runs the puzzles on `workers` threads. Parts run quietly and are printed in order once their puzzle is done.
+fn run_parallel(
+    puzzles: &[PuzzleId],
+    mut jobs: Vec<Job>,
+    options: &RunOptions,
+    timeout: Option<Duration>,
+    workers: usize,
+    results: &mut Results,
+) {
+    let quiet = RunOptions {
+        is_quiet: true,
+        ..options.clone()
+    };
+
+    let tasks: Vec<(usize, &Solution, String)> = jobs
+        .iter_mut()
+        .enumerate()
+        .filter_map(|(i, job)| match job {
+            Job::Pending {
+                solution, input, ..
+            } => Some((i, *solution, mem::take(input))),
+            _ => None,
+        })
+        .collect();
+
+    let next_task = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        let (tasks, next_task, quiet) = (&tasks, &next_task, &quiet);
+
+        for _ in 0..workers.min(tasks.len()) {
+            let sender = sender.clone();
+            scope.spawn(move || {
+                while let Some((i, solution, input)) =
+                    tasks.get(next_task.fetch_add(1, Ordering::Relaxed))
+                {
+                    // the receiver only goes away if the main thread panicked.
+                    let _ = sender.send((*i, run_solution(solution, input, quiet, timeout)));
+                }
+            });
+        }
+        drop(sender);
+
+        let mut finished: BTreeMap<usize, Vec<PartRecord>> = BTreeMap::new();
+
+        for (i, (&puzzle, job)) in puzzles.iter().zip(jobs).enumerate() {
+            let job = match job {
+                Job::Pending { key, .. } => {
+                    while !finished.contains_key(&i) {
+                        let (j, records) = receiver
+                            .recv()
+                            .expect("every pending puzzle should be run by a worker");
+                        finished.insert(j, records);
+                    }
+                    Job::Done {
+                        records: finished.remove(&i).unwrap_or_default(),
+                        key,
+                    }
+                }
+                job => job,
+            };
+
+            print_header(puzzle, i == 0, options.format);
+            if let Job::Done { records, .. } = &job {
+                print_records(records, options.format);
+            }
+            results.report(puzzle, job, options.format);
+        }
+    });
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+/// Runs both parts of a solution. With a `timeout`, every part runs in a child process that is killed
+/// once it takes longer, so a part that never finishes or crashes its process is recorded instead of ending the run.
+fn run_solution(
+    solution: &Solution,
+    input: &str,
+    options: &RunOptions,
+    timeout: Option<Duration>,
+) -> Vec<PartRecord> {
+    let Some(timeout) = timeout else {
+        return solution.run(input, options);
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    // the child reads the input itself, output it prints besides the record is only shown in sequential runs.
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let run = isolation::run_part(solution.puzzle, part, options, timeout);
+            if !options.is_quiet {
+                for line in &run.output {
+                    println!("{line}");
+                }
+                print_records(slice::from_ref(&run.record), options.format);
+            }
+            run.record
+        })
+        .collect()
+}
+
+/// Prints how each part changed relative to the saved baseline.
+/// Returns `false` if any part got slower by more than `threshold_percent`.
+fn compare_to_baseline(entries: &[HistoryEntry], threshold_percent: f64) -> bool {
+    let baseline = match history::read_baseline() {
+        Ok(baseline) => baseline,
+        Err(_) => {
+            eprintln!("No baseline found. Run `cargo time --save-baseline` to create one.");
+            return true;
         }
+    };
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    let comparisons = history::compare(&baseline, entries);
+    let regressions = comparisons
+        .iter()
+        .filter(|c| c.is_regression(threshold_percent))
+        .count();
 
-        if is_release {
-            args.push("--release");
-        }
+    eprintln!("\n{ANSI_BOLD}Compared to baseline:{ANSI_RESET}");
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
+    for comparison in &comparisons {
+        let change = comparison.change_percent();
+        let flag = if comparison.is_regression(threshold_percent) {
+            " ⚠ slower"
+        } else {
+            ""
+        };
+        eprintln!(
+            "Day {} Part {}: {:.1?} → {:.1?} ({change:+.1}%){flag}",
+            comparison.day,
+            comparison.part,
+            Duration::from_nanos(comparison.baseline_nanos),
+            Duration::from_nanos(comparison.current_nanos),
+        );
+    }
+
+    if regressions > 0 {
+        eprintln!(
+            "{regressions} part(s) got slower than the baseline by more than {threshold_percent}%."
+        );
+    }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+    regressions == 0
+}
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+/// What to do with a puzzle, decided before any puzzle runs.
+enum Job<'a> {
+    /// The puzzle has not been scaffolded yet.
+    Unscaffolded,
+    /// The input of the puzzle could not be read.
+    Skipped(InputError),
+    /// The results of the puzzle are cached.
+    Cached(Vec<PartRecord>),
+    /// The puzzle needs to run.
+    Pending {
+        solution: &'a Solution,
+        input: String,
+        key: Option<String>,
+    },
+    /// The puzzle ran, its records are stored in the cache with `key`.
+    Done {
+        records: Vec<PartRecord>,
+        key: Option<String>,
+    },
+}
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+/// Reads the input of a puzzle and looks up its results in the cache, unless `is_fresh` is set.
+fn prepare<'a>(
+    registry: &'a Registry,
+    puzzle: PuzzleId,
+    options: &RunOptions,
+    cache: &Cache,
+    is_fresh: bool,
+) -> Job<'a> {
+    let Some(solution) = registry.get(puzzle) else {
+        return Job::Unscaffolded;
+    };
 
-        let mut output = vec![];
+    let input = match input::read_input(puzzle) {
+        Ok(input) => input,
+        Err(e) => return Job::Skipped(e),
+    };
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+    // sources that can not be read are not cached, e.g. if the binary runs outside of the project.
+    let key = cache::key(Path::new("."), puzzle, &input, options).ok();
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+    if !is_fresh {
+        if let Some(records) = key.as_ref().and_then(|key| cache.get(puzzle, key)) {
+            return Job::Cached(records.to_vec());
         }
+    }
 
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
+    Job::Pending {
+        solution,
+        input,
+        key,
     }
+}
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+/// The results of every puzzle, collected in order.
+#[derive(Default)]
+struct Results {
+    cache: Cache,
+    cache_hits: usize,
+    timings: Vec<Timings>,
+    all_records: Vec<PartRecord>,
+    fresh_records: Vec<PartRecord>,
+}
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+impl Results {
+    /// Adds the outcome of a puzzle, records of puzzles that ran are expected to be printed already.
+    fn report(&mut self, puzzle: PuzzleId, job: Job, format: OutputFormat) {
+        match job {
+            Job::Cached(records) => {
+                self.cache_hits += 1;
+                print_records(&records, format);
+                if format == OutputFormat::Text {
+                    println!("{ANSI_ITALIC}(cached){ANSI_RESET}");
                 }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                self.timings.push(Timings {
+                    title: readme_benchmarks::read_title(puzzle),
+                    ..collect_timings(&records, puzzle)
+                });
+                self.all_records.extend(records);
+            }
+            Job::Done { records, key } => {
+                // a part that timed out might finish with more time, so it is run again next time.
+                let is_complete = records.iter().all(|r| r.status != PartStatus::TimedOut);
+                if let Some(key) = key.filter(|_| is_complete) {
+                    // written after every day, so an interrupted run keeps the days that finished.
+                    self.cache.insert(puzzle, key, records.clone());
+                    if self.cache.write().is_err() {
+                        eprintln!("Failed to write result cache.");
+                    }
+                }
+                self.timings.push(Timings {
+                    title: readme_benchmarks::read_title(puzzle),
+                    ..collect_timings(&records, puzzle)
+                });
+                self.fresh_records.extend(records.iter().cloned());
+                self.all_records.extend(records);
+            }
+            Job::Pending { .. } => unreachable!("puzzles are run before they are reported"),
+            Job::Unscaffolded | Job::Skipped(_) => {
+                if let Job::Skipped(e) = job {
+                    eprintln!("Skipping {puzzle}: {e}");
                 }
+                match format {
+                    OutputFormat::Text => println!("Not solved."),
+                    OutputFormat::Json => {
+                        println!("{}", PartRecord::unsolved(puzzle, 1).to_json());
+                        println!("{}", PartRecord::unsolved(puzzle, 2).to_json());
+                    }
+                }
+            }
+        }
+    }
+}
 
-                timings.total_nanos += nanos;
-            });
+fn print_header(puzzle: PuzzleId, is_first: bool, format: OutputFormat) {
+    if format == OutputFormat::Text {
+        if !is_first {
+            println!();
+        }
 
-        timings
+        println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+        println!("------");
     }
+}
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+fn print_records(records: &[PartRecord], format: OutputFormat) {
+    for record in records {
+        match format {
+            OutputFormat::Text => print_record(record),
+            OutputFormat::Json => println!("{}", record.to_json()),
+        }
     }
+}
+
+fn collect_timings(records: &[PartRecord], puzzle: PuzzleId) -> Timings {
+    let mut timings = Timings {
+        puzzle,
+        title: None,
+        part_1_solved: false,
+        part_2_solved: false,
+        part_1: None,
+        part_2: None,
+        part_1_alloc: None,
+        part_2_alloc: None,
+        total_nanos: 0_f64,
+    };
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+    for record in records {
+        // parts that panicked or timed out are listed, so the table shows which parts need attention.
+        let timing_str = match record.status {
+            PartStatus::Solved => match record.stats {
+                Some(stats) => format!("{:.1?} ± {:.1?}", stats.median(), stats.std_dev()),
+                None => format!("{:.1?}", record.duration()),
+            },
+            PartStatus::Panicked => "panicked".to_string(),
+            PartStatus::TimedOut => "timed out".to_string(),
+            PartStatus::Unsolved | PartStatus::Failed => continue,
+        };
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+        let alloc_str = record.alloc.map(|alloc| alloc.summary());
+        let is_solved = record.status == PartStatus::Solved;
 
-        Some((str_timing, parsed_timing))
-    }
+        match record.part {
+            1 => {
+                timings.part_1_solved = is_solved;
+                timings.part_1 = Some(timing_str);
+                timings.part_1_alloc = alloc_str;
+            }
+            2 => {
+                timings.part_2_solved = is_solved;
+                timings.part_2 = Some(timing_str);
+                timings.part_2_alloc = alloc_str;
+            }
+            _ => {}
+        }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+        if is_solved {
+            #[allow(clippy::cast_precision_loss)]
+            {
+                timings.total_nanos += record.duration_nanos as f64;
+            }
+        }
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
+    timings
+}
 
-        use crate::day;
+/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
+#[cfg(feature = "test_lib")]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr) => {{
+        let (a, b) = (&$a, &$b);
+        assert!(
+            (*a - *b).abs() < 1.0e-6,
+            "{} is not approximately equal to {}",
+            *a,
+            *b
+        );
+    }};
+}
 
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::collect_timings;
+
+    use crate::day;
+    use crate::template::part_record;
+    use crate::template::protocol::PartStatus;
+
+    #[test]
+    fn test_well_formed() {
+        let res = collect_timings(
+            &[
+                part_record(1, Some("10"), 74, PartStatus::Solved),
+                part_record(2, Some("10"), 74_130_000, PartStatus::Solved),
+            ],
+            day!(1).into(),
+        );
+        assert_approx_eq!(res.total_nanos, 74130074_f64);
+        assert_eq!(res.part_1.unwrap(), "74.0ns");
+        assert_eq!(res.part_2.unwrap(), "74.1ms");
+        assert!(res.part_1_solved && res.part_2_solved);
+    }
 
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+    #[test]
+    fn test_single_part() {
+        let res = collect_timings(
+            &[
+                part_record(1, Some("10"), 2_000_000_000, PartStatus::Solved),
+                part_record(2, Some("10"), 0, PartStatus::Unsolved),
+            ],
+            day!(1).into(),
+        );
+        assert_approx_eq!(res.total_nanos, 2000000000_f64);
+        assert_eq!(res.part_1.unwrap(), "2.0s");
+        assert_eq!(res.part_2.is_none(), true);
+    }
 
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+    #[test]
+    fn test_missing_parts() {
+        let res = collect_timings(
+            &[
+                part_record(1, Some("10"), 0, PartStatus::Unsolved),
+                part_record(2, Some("10"), 5, PartStatus::Panicked),
+            ],
+            day!(1).into(),
+        );
+        assert_approx_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.unwrap(), "panicked");
+        assert!(!res.part_1_solved && !res.part_2_solved);
+    }
+
+    #[test]
+    fn test_timed_out_part() {
+        let res = collect_timings(
+            &[
+                part_record(1, Some("10"), 74, PartStatus::Solved),
+                part_record(2, Some("10"), 60_000_000_000, PartStatus::TimedOut),
+            ],
+            day!(1).into(),
+        );
+        assert_approx_eq!(res.total_nanos, 74_f64);
+        assert_eq!(res.part_1.unwrap(), "74.0ns");
+        assert_eq!(res.part_2.unwrap(), "timed out");
     }
 }
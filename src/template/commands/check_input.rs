@@ -0,0 +1,71 @@
+use std::path::Path;
+use std::process;
+
+use crate::template::input::{self, Problem};
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::{Day, PuzzleId, Year};
+
+pub fn handle() {
+    let data_dir = Path::new("data");
+
+    let files = match input::find_files(data_dir) {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("Failed to list input files: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut problem_count = 0;
+
+    for path in &files {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) => {
+                println!(
+                    "{ANSI_BOLD}{}{ANSI_RESET}: could not read file: {e}",
+                    path.display()
+                );
+                problem_count += 1;
+                continue;
+            }
+        };
+
+        let is_example = path.parent().and_then(Path::file_name) == Some("examples".as_ref());
+        let puzzle = get_puzzle(path).map_or(String::new(), |puzzle| format!(" {puzzle}"));
+
+        for problem in input::lint(&contents) {
+            let hint = match problem {
+                Problem::Empty if is_example => {
+                    format!(" (example missing, run `cargo examples{puzzle}`)")
+                }
+                Problem::Empty => format!(" (input missing, run `cargo download{puzzle}`)"),
+                _ => String::new(),
+            };
+            println!("{ANSI_BOLD}{}{ANSI_RESET}: {problem}{hint}", path.display());
+            problem_count += 1;
+        }
+    }
+
+    println!("---");
+    if problem_count > 0 {
+        println!(
+            "Found {problem_count} problem(s) in {} file(s). Set `AOC_NORMALIZE_INPUT = \"true\"` in `.cargo/config.toml` to fix line endings and whitespace when reading files.",
+            files.len()
+        );
+        process::exit(1);
+    }
+    println!("🎄 Checked {} file(s), no problems found.", files.len());
+}
+
+/// The puzzle a file belongs to, e.g. `data/2022/inputs/07.txt` or `data/examples/07.1.txt`.
+fn get_puzzle(path: &Path) -> Option<PuzzleId> {
+    let name = path.file_name()?.to_str()?;
+    let day: Day = name.split('.').next()?.parse().ok()?;
+
+    let year_dir = path.parent()?.parent()?.file_name()?.to_str()?;
+    match year_dir.parse::<Year>() {
+        Ok(year) => Some(PuzzleId::new(year, day)),
+        Err(_) => Some(day.into()),
+    }
+}
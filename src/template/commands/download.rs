@@ -1,15 +1,50 @@
-use crate::template::aoc_cli;
-use crate::Day;
+use std::fs;
+use std::path::Path;
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+use crate::template::service::{self, PuzzleService, ServiceError};
+use crate::PuzzleId;
+
+pub fn handle(puzzle: PuzzleId) {
+    let service = service::from_env_or_exit();
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = run(service.as_ref(), puzzle, Path::new("data")) {
+        eprintln!("failed to download puzzle: {e}");
         process::exit(1);
     };
 }
+
+/// Writes the input and the description of a puzzle to `data_dir`.
+pub fn run(
+    service: &dyn PuzzleService,
+    puzzle: PuzzleId,
+    data_dir: &Path,
+) -> Result<(), ServiceError> {
+    let input = service.fetch_input(puzzle)?;
+    let description = service.fetch_description(puzzle)?;
+
+    let input_path = data_dir
+        .join(puzzle.data_folder("inputs"))
+        .join(format!("{}.txt", puzzle.day));
+    let puzzle_path = data_dir
+        .join(puzzle.data_folder("puzzles"))
+        .join(format!("{}.md", puzzle.day));
+
+    for (path, contents) in [(&input_path, input), (&puzzle_path, description)] {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, contents)?;
+    }
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
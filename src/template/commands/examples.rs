@@ -0,0 +1,166 @@
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use crate::template::examples::{self, Error};
+use crate::PuzzleId;
+
+pub fn handle(puzzle: PuzzleId, force: bool) {
+    if let Err(e) = run(puzzle, Path::new("data"), force) {
+        eprintln!("failed to extract examples: {e:?}");
+        process::exit(1);
+    };
+}
+
+/// Writes the examples of the puzzle description in `data_dir` to its example files and
+/// records their expected answers in the day's manifest.
+/// Example files that were changed by hand are kept unless `force` is set,
+/// empty ones (e.g. created by `scaffold`) are overwritten.
+pub fn run(puzzle: PuzzleId, data_dir: &Path, force: bool) -> Result<(), Error> {
+    let puzzle_path = data_dir
+        .join(puzzle.data_folder("puzzles"))
+        .join(format!("{}.md", puzzle.day));
+    let markdown = fs::read_to_string(&puzzle_path)?;
+    let extraction = examples::extract(puzzle, &markdown);
+
+    if extraction.examples.is_empty() {
+        println!(
+            "No examples found in \"{}\", is the puzzle downloaded?",
+            puzzle_path.display()
+        );
+        return Ok(());
+    }
+
+    let examples_dir = data_dir.join(puzzle.data_folder("examples"));
+    fs::create_dir_all(&examples_dir)?;
+
+    let mut kept = vec![];
+    for example in &extraction.examples {
+        let path = examples_dir.join(&example.file);
+        match fs::read_to_string(&path) {
+            Ok(content) if content == example.content => {}
+            // `scaffold` creates an empty example file, which is filled in like a missing one.
+            Ok(content) if !force && !content.trim().is_empty() => {
+                println!(
+                    "Kept \"{}\", it differs from the example in the puzzle. Pass --force to overwrite it.",
+                    path.display()
+                );
+                kept.push(example.file.as_str());
+            }
+            _ => {
+                fs::write(&path, &example.content)?;
+                println!("Wrote example to \"{}\".", path.display());
+            }
+        }
+    }
+
+    let manifest_path = examples::get_path_for_manifest(data_dir, puzzle);
+    let mut manifest = examples::read_manifest(&manifest_path)?;
+    for example in &extraction.examples {
+        if !kept.contains(&example.file.as_str()) {
+            manifest.entry(&example.file);
+        }
+    }
+    for (part, file, answer) in &extraction.answers {
+        if !kept.contains(&file.as_str()) {
+            manifest.entry(file).set(*part, answer);
+            println!("Expecting {answer} for part {part} of \"{file}\".");
+        }
+    }
+    examples::write_manifest(&manifest_path, &manifest)?;
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote manifest to \"{}\".",
+        manifest_path.display()
+    );
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::run;
+    use crate::template::examples::{get_path_for_manifest, read_manifest};
+    use crate::template::fixture_dir;
+    use crate::{day, PuzzleId};
+
+    const PART_ONE: &str =
+        "For example:\n\n```\n1 2\n```\n\nThe sum is `*3*`.\n\n*What is the sum?*\n";
+    const PART_TWO: &str =
+        "\n\\--- Part Two ---\n\nFor example:\n\n```\n3 4\n```\n\nThe product is `*12*`.\n";
+
+    #[test]
+    fn writes_examples_and_manifest() {
+        let data = fixture_dir("examples_data");
+        let puzzle = PuzzleId::from(day!(4));
+        fs::create_dir_all(data.join("puzzles")).unwrap();
+        fs::write(data.join("puzzles").join("04.md"), PART_ONE).unwrap();
+
+        run(puzzle, &data, false).unwrap();
+        assert_eq!(
+            fs::read_to_string(data.join("examples").join("04.txt")).unwrap(),
+            "1 2\n"
+        );
+
+        // running again once part two is unlocked adds its example.
+        fs::write(
+            data.join("puzzles").join("04.md"),
+            format!("{PART_ONE}{PART_TWO}"),
+        )
+        .unwrap();
+        run(puzzle, &data, false).unwrap();
+        assert_eq!(
+            fs::read_to_string(data.join("examples").join("04.1.txt")).unwrap(),
+            "3 4\n"
+        );
+
+        let manifest = read_manifest(&get_path_for_manifest(&data, puzzle)).unwrap();
+        assert_eq!(manifest.examples.len(), 2);
+        assert_eq!(manifest.examples[0].get(1), Some("3"));
+        assert_eq!(manifest.examples[1].get(2), Some("12"));
+    }
+
+    #[test]
+    fn keeps_changed_examples() {
+        let data = fixture_dir("examples_keep");
+        let puzzle = PuzzleId::from(day!(4));
+        fs::create_dir_all(data.join("puzzles")).unwrap();
+        fs::create_dir_all(data.join("examples")).unwrap();
+        fs::write(data.join("puzzles").join("04.md"), PART_ONE).unwrap();
+        fs::write(data.join("examples").join("04.txt"), "5 6\n").unwrap();
+
+        run(puzzle, &data, false).unwrap();
+        assert_eq!(
+            fs::read_to_string(data.join("examples").join("04.txt")).unwrap(),
+            "5 6\n"
+        );
+        let manifest = read_manifest(&get_path_for_manifest(&data, puzzle)).unwrap();
+        assert!(manifest.examples.is_empty());
+
+        run(puzzle, &data, true).unwrap();
+        assert_eq!(
+            fs::read_to_string(data.join("examples").join("04.txt")).unwrap(),
+            "1 2\n"
+        );
+    }
+
+    #[test]
+    fn fills_scaffolded_examples() {
+        let data = fixture_dir("examples_scaffolded");
+        let puzzle = PuzzleId::from(day!(4));
+        fs::create_dir_all(data.join("puzzles")).unwrap();
+        fs::create_dir_all(data.join("examples")).unwrap();
+        fs::write(data.join("puzzles").join("04.md"), PART_ONE).unwrap();
+        fs::write(data.join("examples").join("04.txt"), "\n").unwrap();
+
+        run(puzzle, &data, false).unwrap();
+        assert_eq!(
+            fs::read_to_string(data.join("examples").join("04.txt")).unwrap(),
+            "1 2\n"
+        );
+        let manifest = read_manifest(&get_path_for_manifest(&data, puzzle)).unwrap();
+        assert_eq!(manifest.examples[0].get(1), Some("3"));
+    }
+}
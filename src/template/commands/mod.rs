@@ -1,5 +1,9 @@
 pub mod all;
+pub mod check_input;
 pub mod download;
+pub mod examples;
 pub mod read;
+pub mod run_part;
 pub mod scaffold;
 pub mod solve;
+pub mod verify;
@@ -1,16 +1,25 @@
+use std::io::{self, Write};
 use std::process;
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::service::{self, PuzzleService, ServiceError};
+use crate::PuzzleId;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(puzzle: PuzzleId) {
+    let service = service::from_env_or_exit();
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = run(service.as_ref(), puzzle, &mut io::stdout()) {
+        eprintln!("failed to read puzzle: {e}");
         process::exit(1);
     };
 }
+
+/// Writes the description of a puzzle to `out`.
+pub fn run(
+    service: &dyn PuzzleService,
+    puzzle: PuzzleId,
+    out: &mut impl Write,
+) -> Result<(), ServiceError> {
+    let description = service.fetch_description(puzzle)?;
+    writeln!(out, "{}", description.trim_end())?;
+    Ok(())
+}
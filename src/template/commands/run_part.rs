@@ -0,0 +1,34 @@
+use std::process;
+
+use crate::template::input;
+use crate::template::protocol::OutputFormat;
+use crate::template::registry::Registry;
+use crate::template::runner::RunOptions;
+use crate::PuzzleId;
+
+/// Runs a single part against its puzzle input and prints its record as JSON.
+/// Used by `all` to run parts in child processes, see [`crate::template::isolation`].
+pub fn handle(registry: &Registry, puzzle: PuzzleId, part: u8, options: &RunOptions) {
+    let Some(solution) = registry.get(puzzle) else {
+        eprintln!("{puzzle} has not been scaffolded yet.");
+        process::exit(1);
+    };
+
+    if part != 1 && part != 2 {
+        eprintln!("expecting a part number of 1 or 2, got {part}");
+        process::exit(1);
+    }
+
+    let input = input::read_input(puzzle).unwrap_or_else(|e| {
+        eprintln!("Failed to read input of {puzzle}: {e}");
+        process::exit(1);
+    });
+
+    let options = RunOptions {
+        format: OutputFormat::Json,
+        is_quiet: false,
+        ..options.clone()
+    };
+
+    solution.run_part(part, &input, &options);
+}
@@ -1,12 +1,14 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::Day;
+use crate::template::get_data_path;
+use crate::PuzzleId;
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(PUZZLE_ARGS);
 
 pub fn part_one(input: &str) -> Option<u32> {
     None
@@ -16,36 +18,96 @@ pub fn part_two(input: &str) -> Option<u32> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+advent_of_code::example_tests!();
+"#;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+/// Templates for the recurring shapes of puzzle inputs, selected with `--template <name>`.
+const BUILTIN_TEMPLATES: [(&str, &str); 4] = [
+    ("grid", include_str!("../templates/grid.rs.tmpl")),
+    ("graph", include_str!("../templates/graph.rs.tmpl")),
+    ("lines", include_str!("../templates/lines.rs.tmpl")),
+    ("sections", include_str!("../templates/sections.rs.tmpl")),
+];
+
+/// Directory with user templates, `templates/<name>.rs` takes precedence over a built-in template of the same name.
+static USER_TEMPLATE_DIR: &str = "templates";
+
+/// Resolves the module template to scaffold with, [`None`] selects the default template.
+fn get_template(name: Option<&str>, user_template_dir: &Path) -> Result<String, String> {
+    let Some(name) = name else {
+        return Ok(MODULE_TEMPLATE.into());
+    };
+
+    let user_template = user_template_dir.join(format!("{name}.rs"));
+    if user_template.exists() {
+        return fs::read_to_string(&user_template)
+            .map_err(|e| format!("could not read \"{}\": {e}", user_template.display()));
     }
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+    if let Some((_, template)) = BUILTIN_TEMPLATES.iter().find(|(n, _)| *n == name) {
+        return Ok((*template).into());
+    }
+
+    let mut available: Vec<String> = BUILTIN_TEMPLATES.iter().map(|(n, _)| (*n).into()).collect();
+    if let Ok(entries) = fs::read_dir(user_template_dir) {
+        available.extend(entries.filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension()? == "rs").then(|| path.file_stem()?.to_str().map(Into::into))?
+        }));
     }
+    available.sort();
+    available.dedup();
+
+    Err(format!(
+        "unknown template \"{name}\", expecting one of: {}",
+        available.join(", ")
+    ))
 }
-"#;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
+    create_parent_dir(path)?;
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    create_parent_dir(path)?;
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+fn create_parent_dir(path: &Path) -> Result<(), std::io::Error> {
+    match path.parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
+    }
+}
+
+/// The arguments of the `solution!` macro, the year is only needed for puzzles outside the home year.
+fn get_macro_args(puzzle: PuzzleId) -> String {
+    let day = puzzle.day.into_inner();
+    if puzzle.is_home() {
+        day.to_string()
+    } else {
+        format!("{}, {day}", puzzle.year)
+    }
+}
+
+pub fn handle(puzzle: PuzzleId, template: Option<&str>) {
+    let template = match get_template(template, Path::new(USER_TEMPLATE_DIR)) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
+
+    let file_name = format!("{}.txt", puzzle.day);
+    let input_path = get_data_path("inputs", puzzle, &file_name);
+    let example_path = get_data_path("examples", puzzle, &file_name);
+    let module_path = PathBuf::from(format!("src/bin/{}.rs", puzzle.bin_name()));
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -56,12 +118,12 @@ pub fn handle(day: Day) {
     };
 
     match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
+        template
+            .replace("PUZZLE_ARGS", &get_macro_args(puzzle))
             .as_bytes(),
     ) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -71,7 +133,7 @@ pub fn handle(day: Day) {
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
@@ -81,7 +143,7 @@ pub fn handle(day: Day) {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -90,5 +152,39 @@ pub fn handle(day: Day) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!("🎄 Type `cargo solve {puzzle}` to run your solution.");
+    println!("🎄 Type `cargo examples {puzzle}` after downloading the puzzle to test it with its examples.");
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::{get_template, BUILTIN_TEMPLATES, MODULE_TEMPLATE};
+    use crate::template::fixture_dir;
+
+    #[test]
+    fn resolves_builtin_templates() {
+        let dir = fixture_dir("scaffold_builtin");
+        assert_eq!(get_template(None, &dir).unwrap(), MODULE_TEMPLATE);
+
+        for (name, template) in BUILTIN_TEMPLATES {
+            assert_eq!(get_template(Some(name), &dir).unwrap(), template);
+            assert!(template.starts_with("advent_of_code::solution!(PUZZLE_ARGS);"));
+            assert!(template.ends_with("advent_of_code::example_tests!();\n"));
+        }
+    }
+
+    #[test]
+    fn prefers_user_templates() {
+        let dir = fixture_dir("scaffold_user");
+        fs::write(dir.join("grid.rs"), "my grid").unwrap();
+        fs::write(dir.join("intcode.rs"), "my intcode").unwrap();
+
+        assert_eq!(get_template(Some("grid"), &dir).unwrap(), "my grid");
+        assert_eq!(get_template(Some("intcode"), &dir).unwrap(), "my intcode");
+
+        let error = get_template(Some("maze"), &dir).unwrap_err();
+        assert!(error.ends_with("graph, grid, intcode, lines, sections"));
+    }
 }
@@ -1,25 +1,135 @@
-use std::process::{Command, Stdio};
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
 
-use crate::Day;
+use crate::template::{
+    alloc, cache, examples, input::InputSource, protocol::OutputFormat, registry::Registry,
+    runner::RunOptions, ANSI_CLEAR,
+};
+use crate::PuzzleId;
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// How often `--watch` checks the watched files for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub fn handle(registry: &Registry, puzzle: PuzzleId, release: bool, options: &RunOptions) {
+    if options.submit.is_some() && !options.input.is_canonical() {
+        eprintln!(
+            "Can not submit an answer computed from {}, only answers for the puzzle input are submitted.",
+            options.input
+        );
+        process::exit(1);
+    }
+
+    // the main binary contains every solution, run in-process if it was built with the requested profile
+    // and, to count allocations, with the counting allocator.
+    if release != cfg!(debug_assertions) && (!options.count_allocs || alloc::IS_AVAILABLE) {
+        if let Some(solution) = registry.get(puzzle) {
+            match options.input.read(puzzle) {
+                Ok(input) => {
+                    solution.run(&input, options);
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+    }
+
+    run_binary(puzzle, release, options);
+}
+
+/// Rebuilds and reruns the puzzle and its example tests whenever its sources or data change.
+/// The solution is always run from its own binary, as the registry of the main binary does not see the changes.
+pub fn watch(puzzle: PuzzleId, release: bool, options: &RunOptions) {
+    if options.submit.is_some() {
+        eprintln!(
+            "Can not submit in watch mode, the answer would be submitted after every change."
+        );
+        process::exit(1);
+    }
+
+    if options.input == InputSource::Stdin {
+        eprintln!(
+            "Can not watch stdin, save the input to a file and pass it with `--input <path>`."
+        );
+        process::exit(1);
+    }
+
+    let root = Path::new(".");
+    let mut last_snapshot = None;
+
+    loop {
+        let mut files = watched_files(root, puzzle);
+        if let InputSource::File(path) = &options.input {
+            files.push(path.clone());
+        }
+
+        // taken before running, so changes made during a run trigger another one.
+        let snapshot = snapshot(&files);
+
+        if last_snapshot.as_ref() != Some(&snapshot) {
+            last_snapshot = Some(snapshot);
+
+            print!("{ANSI_CLEAR}");
+            let _ = io::stdout().flush();
+
+            run_binary(puzzle, release, options);
+            println!();
+            run_example_tests(puzzle, release);
+            println!("Watching {puzzle} for changes, press Ctrl-C to stop.");
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn run_binary(puzzle: PuzzleId, release: bool, options: &RunOptions) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    if options.count_allocs {
+        cmd_args.push("--features".to_string());
+        cmd_args.push("count_allocs".to_string());
+    }
+
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if let Some(submit_part) = options.submit {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
-    if time {
+    if options.force_submit {
+        cmd_args.push("--force".to_string());
+    }
+
+    if options.is_timed {
         cmd_args.push("--time".to_string());
+        cmd_args.push("--warmup".to_string());
+        cmd_args.push(options.bench.warmup.to_string());
+        cmd_args.push("--budget".to_string());
+        cmd_args.push(options.bench.budget.as_millis().to_string());
+    }
+
+    if options.count_allocs {
+        cmd_args.push("--alloc".to_string());
+    }
+
+    if options.format != OutputFormat::Text {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(options.format.to_string());
     }
 
+    cmd_args.extend(options.input.to_args());
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -29,3 +139,147 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Runs the tests generated from the puzzle's example manifest, see [`crate::example_tests`].
+fn run_example_tests(puzzle: PuzzleId, release: bool) {
+    let mut cmd_args = vec!["test".to_string(), "--bin".to_string(), puzzle.bin_name()];
+
+    if release {
+        cmd_args.push("--release".to_string());
+    }
+
+    cmd_args.push("--".to_string());
+    cmd_args.push("examples::".to_string());
+
+    let mut cmd = Command::new("cargo")
+        .args(&cmd_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .unwrap();
+
+    cmd.wait().unwrap();
+}
+
+/// Lists the files that affect a puzzle's solution below the crate `root`: its binary, the library
+/// sources and its input and example files, e.g. `data/inputs/12.txt`, `data/examples/12.1.txt` and `data/examples/12.toml`.
+fn watched_files(root: &Path, puzzle: PuzzleId) -> Vec<PathBuf> {
+    let mut files = vec![root
+        .join("src")
+        .join("bin")
+        .join(format!("{}.rs", puzzle.bin_name()))];
+    files.extend(cache::library_sources(root));
+
+    // other files in `data`, e.g. the answers, do not affect a run without `--submit`.
+    let data_dir = root.join("data");
+    let prefix = format!("{}.", puzzle.day);
+    for folder in ["inputs", "examples"] {
+        let Ok(entries) = data_dir.join(puzzle.data_folder(folder)).read_dir() else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_puzzle_file = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix));
+            if is_puzzle_file && path.extension().is_some_and(|ext| ext == "txt") {
+                files.push(path);
+            }
+        }
+    }
+    // watched even before it exists, so adding the manifest triggers a run.
+    files.push(examples::get_path_for_manifest(&data_dir, puzzle));
+
+    files.sort();
+    files
+}
+
+/// Records the modification times of the files, files that do not exist are left out.
+fn snapshot(files: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    files
+        .iter()
+        .filter_map(|path| {
+            let modified = path.metadata().and_then(|m| m.modified()).ok()?;
+            Some((path.clone(), modified))
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs::{self, File};
+    use std::time::{Duration, SystemTime};
+
+    use super::{snapshot, watched_files};
+    use crate::template::{fixture_dir, write_fixture};
+    use crate::{day, year, PuzzleId};
+
+    #[test]
+    fn watches_sources_and_data_of_the_puzzle() {
+        let root = fixture_dir("watched_files");
+        for file in [
+            "src/main.rs",
+            "src/lib.rs",
+            "src/day.rs",
+            "src/bin/03.rs",
+            "src/bin/04.rs",
+            "src/template/mod.rs",
+            "src/template/commands/solve.rs",
+            "data/inputs/03.txt",
+            "data/inputs/04.txt",
+            "data/examples/03.txt",
+            "data/examples/03.1.txt",
+            "data/examples/03.toml",
+            "data/puzzles/03.md",
+            "data/answers/03.toml",
+            "data/submissions/03.txt",
+            "data/2022/inputs/03.txt",
+        ] {
+            write_fixture(root.join(file), "");
+        }
+
+        let files: Vec<String> = watched_files(&root, day!(3).into())
+            .iter()
+            .map(|path| path.strip_prefix(&root).unwrap().display().to_string())
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                "data/examples/03.1.txt",
+                "data/examples/03.toml",
+                "data/examples/03.txt",
+                "data/inputs/03.txt",
+                "src/bin/03.rs",
+                "src/day.rs",
+                "src/lib.rs",
+                "src/template/commands/solve.rs",
+                "src/template/mod.rs",
+            ]
+        );
+
+        let files = watched_files(&root, PuzzleId::new(year!(2022), day!(3)));
+        assert!(files.contains(&root.join("data/2022/inputs/03.txt")));
+        assert!(files.contains(&root.join("data/2022/examples/03.toml")));
+        assert!(!files.contains(&root.join("data/inputs/03.txt")));
+    }
+
+    #[test]
+    fn detects_changes() {
+        let root = fixture_dir("snapshots");
+        let path = root.join("03.txt");
+        write_fixture(&path, "");
+        let files = vec![path.clone()];
+
+        let before = snapshot(&files);
+        assert_eq!(before, snapshot(&files));
+
+        let file = File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(10))
+            .unwrap();
+        assert_ne!(before, snapshot(&files));
+
+        fs::remove_file(&path).unwrap();
+        assert!(snapshot(&files).is_empty());
+    }
+}
@@ -0,0 +1,145 @@
+use std::process;
+
+use crate::template::{
+    answers, input,
+    protocol::{PartRecord, PartStatus},
+    registry::Registry,
+    runner::RunOptions,
+    ANSI_BOLD, ANSI_RESET,
+};
+use crate::PuzzleId;
+
+/// The outcome of checking a part against its accepted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    /// The part returns the accepted answer.
+    Pass,
+    /// The part has an accepted answer, but no longer returns anything.
+    Fail,
+    /// The part returns an answer that differs from the accepted one.
+    Changed,
+    /// The part returns an answer, but none was accepted yet.
+    Unknown,
+}
+
+impl Outcome {
+    fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Pass => "pass",
+            Outcome::Fail => "fail",
+            Outcome::Changed => "changed",
+            Outcome::Unknown => "unknown",
+        }
+    }
+}
+
+/// Returns [`None`] for parts that neither have an accepted answer nor return one.
+fn check(expected: Option<&str>, record: &PartRecord) -> Option<Outcome> {
+    let actual = match record.status {
+        PartStatus::Solved => record.answer.as_deref(),
+        PartStatus::Unsolved | PartStatus::Failed | PartStatus::Panicked | PartStatus::TimedOut => {
+            None
+        }
+    };
+
+    match (expected, actual) {
+        (None, None) => None,
+        (None, Some(_)) => Some(Outcome::Unknown),
+        (Some(_), None) => Some(Outcome::Fail),
+        (Some(expected), Some(actual)) if expected == actual => Some(Outcome::Pass),
+        (Some(_), Some(_)) => Some(Outcome::Changed),
+    }
+}
+
+pub fn handle(registry: &Registry, puzzles: &[PuzzleId]) {
+    let puzzles: Vec<PuzzleId> = if puzzles.is_empty() {
+        registry.iter().map(|solution| solution.puzzle).collect()
+    } else {
+        puzzles.to_vec()
+    };
+
+    let options = RunOptions {
+        is_quiet: true,
+        ..RunOptions::default()
+    };
+
+    let mut failures = 0;
+
+    println!(
+        "{ANSI_BOLD}Puzzle   Part  Expected            Actual              Status{ANSI_RESET}"
+    );
+
+    for puzzle in puzzles {
+        let Some(solution) = registry.get(puzzle) else {
+            continue;
+        };
+
+        let expected = match answers::read(puzzle) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Could not read answers for {puzzle}: {e:?}");
+                process::exit(1);
+            }
+        };
+
+        let input = match input::read_input(puzzle) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping {puzzle}: {e}");
+                continue;
+            }
+        };
+
+        for record in solution.run(&input, &options) {
+            let Some(outcome) = check(expected.get(record.part), &record) else {
+                continue;
+            };
+
+            if matches!(outcome, Outcome::Fail | Outcome::Changed) {
+                failures += 1;
+            }
+
+            println!(
+                "{:<9}{:<6}{:<20}{:<20}{}",
+                puzzle.to_string(),
+                record.part,
+                expected.get(record.part).unwrap_or("-"),
+                record.answer.as_deref().unwrap_or("-"),
+                outcome.as_str()
+            );
+            if let Some(error) = &record.error {
+                println!("{:<15}{error}", "");
+            }
+        }
+    }
+
+    if failures > 0 {
+        println!("---");
+        println!("{failures} part(s) no longer return their accepted answer.");
+        process::exit(1);
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, Outcome};
+    use crate::template::part_record;
+    use crate::template::protocol::PartStatus;
+
+    #[test]
+    fn checks_answers() {
+        let solved = part_record(1, Some("42"), 0, PartStatus::Solved);
+        assert_eq!(check(Some("42"), &solved), Some(Outcome::Pass));
+        assert_eq!(check(Some("41"), &solved), Some(Outcome::Changed));
+        assert_eq!(check(None, &solved), Some(Outcome::Unknown));
+    }
+
+    #[test]
+    fn checks_missing_answers() {
+        let unsolved = part_record(1, None, 0, PartStatus::Unsolved);
+        let panicked = part_record(1, None, 0, PartStatus::Panicked);
+        assert_eq!(check(Some("42"), &unsolved), Some(Outcome::Fail));
+        assert_eq!(check(Some("42"), &panicked), Some(Outcome::Fail));
+        assert_eq!(check(None, &unsolved), None);
+    }
+}
@@ -0,0 +1,138 @@
+/// The error type that solutions return when they can not solve a part, e.g. because the input does not parse.
+use std::fmt::Display;
+
+use nom::error::ErrorKind;
+
+/// Why a part could not be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolutionError {
+    /// A nom parser failed at a position in the input.
+    Parse {
+        kind: ErrorKind,
+        /// The line of the failure, starting at 1.
+        line: usize,
+        /// The column of the failure in characters, starting at 1.
+        column: usize,
+        /// The input line the parser failed on.
+        source_line: String,
+    },
+    /// The input parsed, but does not fit the assumptions of the solution.
+    Invalid(String),
+}
+
+impl SolutionError {
+    /// Locates a nom error in the `input` that was passed to the parser.
+    ///
+    /// ```
+    /// # use advent_of_code::template::SolutionError;
+    /// use nom::error::{Error, ErrorKind};
+    ///
+    /// let error = SolutionError::parse("12\n34\nx6", Error::new("x6", ErrorKind::Digit));
+    /// assert_eq!(error.to_string(), "failed to parse the input at line 3, column 1 (Digit)");
+    /// ```
+    #[must_use]
+    pub fn parse<I: AsRef<str>>(input: &str, error: nom::error::Error<I>) -> Self {
+        // nom errors hold the remaining input, which is a suffix of the input that was parsed.
+        let offset = input.len().saturating_sub(error.input.as_ref().len());
+        let offset = if input.is_char_boundary(offset) {
+            offset
+        } else {
+            0
+        };
+        let before = &input[..offset];
+
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        SolutionError::Parse {
+            kind: error.code,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Renders the error with the offending input line and a caret pointing at the failure.
+    #[must_use]
+    pub fn diagnostic(&self) -> String {
+        match self {
+            SolutionError::Parse {
+                line,
+                column,
+                source_line,
+                ..
+            } => {
+                let number = line.to_string();
+                let gutter = " ".repeat(number.len());
+                format!(
+                    "error: {self}\n{gutter} |\n{number} | {source_line}\n{gutter} | {}^",
+                    " ".repeat(column - 1)
+                )
+            }
+            SolutionError::Invalid(_) => format!("error: {self}"),
+        }
+    }
+}
+
+impl Display for SolutionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolutionError::Parse {
+                kind, line, column, ..
+            } => write!(
+                f,
+                "failed to parse the input at line {line}, column {column} ({kind:?})"
+            ),
+            SolutionError::Invalid(reason) => write!(f, "invalid input: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SolutionError {}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use nom::error::{Error, ErrorKind};
+
+    use super::SolutionError;
+
+    #[test]
+    fn locates_errors() {
+        let input = "32T3K 765\nT55J5 68x\nKK677 28\n";
+        let error = Error::new("x\nKK677 28\n".to_string(), ErrorKind::Tag);
+
+        assert_eq!(
+            SolutionError::parse(input, error),
+            SolutionError::Parse {
+                kind: ErrorKind::Tag,
+                line: 2,
+                column: 9,
+                source_line: "T55J5 68x".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn renders_diagnostics() {
+        let input = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10 x\n";
+        let error = SolutionError::parse(input, Error::new("x\n", ErrorKind::Digit));
+
+        assert_eq!(
+            error.diagnostic(),
+            "error: failed to parse the input at line 10, column 4 (Digit)\n   |\n10 | 10 x\n   |    ^"
+        );
+    }
+
+    #[test]
+    fn locates_errors_at_the_end() {
+        let error = SolutionError::parse("1 2", Error::new("", ErrorKind::Char));
+        assert_eq!(
+            error.diagnostic(),
+            "error: failed to parse the input at line 1, column 4 (Char)\n  |\n1 | 1 2\n  |    ^"
+        );
+    }
+}
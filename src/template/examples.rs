@@ -0,0 +1,427 @@
+/// Module that extracts the examples and their expected answers from a puzzle description,
+/// and keeps them in a manifest next to the example files.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::runner::PartOutput;
+use crate::template::{get_data_path, input};
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// An example file and the answers the puzzle description gives for it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Example {
+    /// The file name below `data/examples`, e.g. `08.txt` or `08.1.txt`.
+    pub file: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+}
+
+impl Example {
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_one = Some(answer.into()),
+            2 => self.part_two = Some(answer.into()),
+            _ => {}
+        }
+    }
+}
+
+/// The examples of a single day, stored as `data/examples/NN.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    #[serde(default, rename = "example")]
+    pub examples: Vec<Example>,
+}
+
+impl Manifest {
+    /// Returns the entry for `file`, adding an empty one if there is none.
+    pub fn entry(&mut self, file: &str) -> &mut Example {
+        let index = match self.examples.iter().position(|e| e.file == file) {
+            Some(index) => index,
+            None => {
+                self.examples.push(Example {
+                    file: file.into(),
+                    ..Example::default()
+                });
+                self.examples.len() - 1
+            }
+        };
+        &mut self.examples[index]
+    }
+}
+
+/// The path of a day's manifest below `data_dir`.
+#[must_use]
+pub fn get_path_for_manifest(data_dir: &Path, puzzle: PuzzleId) -> PathBuf {
+    data_dir
+        .join(puzzle.data_folder("examples"))
+        .join(format!("{}.toml", puzzle.day))
+}
+
+/// Reads a manifest. Days without a manifest have no examples.
+pub fn read_manifest(path: &Path) -> Result<Manifest, Error> {
+    if !path.exists() {
+        return Ok(Manifest::default());
+    }
+    let content = fs::read_to_string(path)?;
+    toml::from_str(&content).map_err(|e| Error::Parser(e.to_string()))
+}
+
+pub fn write_manifest(path: &Path, manifest: &Manifest) -> Result<(), Error> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let content = toml::to_string(manifest).map_err(|e| Error::Parser(e.to_string()))?;
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// Runs a part on an example file and asserts that it returns the expected answer.
+/// Called by the tests that [`example_tests!`](crate::example_tests) generates from the manifest.
+pub fn check<R: PartOutput>(
+    puzzle: PuzzleId,
+    file: &str,
+    func: impl Fn(&str) -> R,
+    expected: &str,
+) {
+    let path = get_data_path("examples", puzzle, file);
+    let input = input::read(&path)
+        .unwrap_or_else(|e| panic!("could not open example file \"{}\": {e}", path.display()));
+    assert!(
+        !input.trim().is_empty(),
+        "example missing, run `cargo examples {puzzle}` or add it to \"{}\"",
+        path.display()
+    );
+
+    let result = func(&input)
+        .into_answer()
+        .unwrap_or_else(|e| panic!("example \"{file}\" failed\n{}", e.diagnostic()));
+    assert_eq!(result.as_deref(), Some(expected), "example \"{file}\"");
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An example found in a puzzle description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractedExample {
+    pub file: String,
+    pub content: String,
+}
+
+/// The examples of a puzzle description and the expected answer of each part, as `(part, file, answer)`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Extraction {
+    pub examples: Vec<ExtractedExample>,
+    pub answers: Vec<(u8, String, String)>,
+}
+
+/// A paragraph or code block of the puzzle description.
+enum Block {
+    Text(String),
+    Code(String),
+}
+
+/// Extracts the examples and expected answers from a puzzle description as written by `cargo download`.
+///
+/// A code block is an example if the paragraph before it introduces an example, the first one is
+/// written to `NN.txt` and the others to `NN.1.txt`, `NN.2.txt`, ... in order of appearance.
+/// The expected answer of a part is the last emphasized code (`` `*42*` ``) of the part. It belongs to the
+/// example that follows it if its paragraph introduces a code block, and to the previous example otherwise.
+#[must_use]
+pub fn extract(puzzle: PuzzleId, markdown: &str) -> Extraction {
+    let mut extraction = Extraction::default();
+    let mut last_example: Option<String> = None;
+
+    for (index, section) in split_parts(markdown).iter().enumerate() {
+        #[allow(clippy::cast_possible_truncation)]
+        let part = index as u8 + 1;
+        let blocks = parse_blocks(section);
+        // the answer of the part, the example it belongs to, and whether it is about the code block that follows.
+        let mut answer: Option<(String, Option<String>, bool)> = None;
+
+        for (i, block) in blocks.iter().enumerate() {
+            match block {
+                Block::Code(code) => {
+                    let is_example = match i.checked_sub(1).map(|i| &blocks[i]) {
+                        Some(Block::Text(text)) => is_example(text, code, &extraction.examples),
+                        _ => false,
+                    };
+                    if !is_example {
+                        continue;
+                    }
+
+                    let file = match extraction.examples.iter().find(|e| e.content == *code) {
+                        Some(example) => example.file.clone(),
+                        None => {
+                            let file = match extraction.examples.len() {
+                                0 => format!("{}.txt", puzzle.day),
+                                n => format!("{}.{n}.txt", puzzle.day),
+                            };
+                            extraction.examples.push(ExtractedExample {
+                                file: file.clone(),
+                                content: code.clone(),
+                            });
+                            file
+                        }
+                    };
+
+                    if let Some((_, example, introduces_code @ true)) = &mut answer {
+                        *example = Some(file.clone());
+                        *introduces_code = false;
+                    }
+                    last_example = Some(file);
+                }
+                Block::Text(text) => {
+                    if let Some((_, _, introduces_code)) = &mut answer {
+                        *introduces_code = false;
+                    }
+                    if text.starts_with("Your puzzle answer was") {
+                        break;
+                    }
+                    if let Some(value) = find_emphasized_code(text).pop() {
+                        let introduces_code = text.ends_with(':')
+                            && matches!(blocks.get(i + 1), Some(Block::Code(_)));
+                        answer = Some((value, last_example.clone(), introduces_code));
+                    }
+                }
+            }
+        }
+
+        if let Some((value, Some(file), _)) = answer {
+            extraction.answers.push((part, file, value));
+        }
+    }
+
+    extraction
+}
+
+/// Whether a code block is an example, judged by the paragraph that introduces it.
+/// Paragraphs like "For example, here is ..." also introduce illustrations, such blocks only count as
+/// examples if they do not use characters that the previous examples do not have.
+fn is_example(introduction: &str, code: &str, examples: &[ExtractedExample]) -> bool {
+    let introduction = introduction.to_lowercase();
+
+    if introduction.ends_with("example:")
+        || introduction.contains("an example")
+        || introduction.contains("another example")
+    {
+        return true;
+    }
+
+    if !introduction.contains("for example") {
+        return false;
+    }
+
+    examples.is_empty()
+        || code
+            .chars()
+            .all(|c| examples.iter().any(|example| example.content.contains(c)))
+}
+
+/// Splits a puzzle description into the text of each part.
+fn split_parts(markdown: &str) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    for line in markdown.lines() {
+        if line
+            .trim_start_matches('\\')
+            .starts_with("--- Part Two ---")
+        {
+            parts.push(String::new());
+        }
+        let part = parts.last_mut().unwrap();
+        part.push_str(line);
+        part.push('\n');
+    }
+    parts
+}
+
+/// Splits the text of a part into paragraphs and fenced code blocks.
+fn parse_blocks(text: &str) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut code: Option<Vec<&str>> = None;
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>| {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Text(paragraph.join(" ")));
+            paragraph.clear();
+        }
+    };
+
+    for line in text.lines() {
+        if line.starts_with("```") {
+            match code.take() {
+                Some(lines) => {
+                    let mut content = lines.join("\n").trim_end().to_string();
+                    content.push('\n');
+                    blocks.push(Block::Code(content));
+                }
+                None => {
+                    flush(&mut paragraph, &mut blocks);
+                    code = Some(vec![]);
+                }
+            }
+        } else if let Some(lines) = &mut code {
+            lines.push(line);
+        } else if line.trim().is_empty() {
+            flush(&mut paragraph, &mut blocks);
+        } else {
+            paragraph.push(line.trim());
+        }
+    }
+    flush(&mut paragraph, &mut blocks);
+
+    blocks
+}
+
+/// Returns the contents of every emphasized code span (`` `*value*` ``) in a paragraph.
+fn find_emphasized_code(text: &str) -> Vec<String> {
+    text.split('`')
+        .skip(1)
+        .step_by(2)
+        .filter_map(|span| span.strip_prefix('*')?.strip_suffix('*'))
+        .filter(|value| !value.is_empty())
+        .map(Into::into)
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract, Manifest};
+    use crate::{day, PuzzleId};
+
+    const PUZZLE: &str = r"\--- Day 8: Haunted Wasteland ---
+----------
+
+This format defines each *node* of the network individually. For example:
+
+```
+RL
+
+AAA = (BBB, CCC)
+ZZZ = (ZZZ, ZZZ)
+
+```
+
+Then, `L` means to choose the *left* element of `CCC`, `*ZZZ*`. You reach `ZZZ` in `*2*` steps.
+
+For example, here is a situation that takes `*6*` steps to reach `ZZZ`:
+
+```
+LLR
+
+AAA = (BBB, BBB)
+
+```
+
+*How many steps are required to reach `ZZZ`?*
+
+Your puzzle answer was `16897`.
+
+\--- Part Two ---
+----------
+
+For example:
+
+```
+LR
+
+11A = (11B, XXX)
+
+```
+
+Step 1: Each ghost chooses its left path:
+
+```
+11A = 11B
+
+```
+
+So, in this example, you end up entirely on nodes that end in `Z` after `*6*` steps.
+
+Your puzzle answer was `16563603485021`.
+";
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        let extraction = extract(PuzzleId::from(day!(8)), PUZZLE);
+
+        let files: Vec<(&str, &str)> = extraction
+            .examples
+            .iter()
+            .map(|e| (e.file.as_str(), e.content.as_str()))
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                ("08.txt", "RL\n\nAAA = (BBB, CCC)\nZZZ = (ZZZ, ZZZ)\n"),
+                ("08.1.txt", "LLR\n\nAAA = (BBB, BBB)\n"),
+                ("08.2.txt", "LR\n\n11A = (11B, XXX)\n"),
+            ]
+        );
+        assert_eq!(
+            extraction.answers,
+            vec![
+                (1, "08.1.txt".into(), "6".into()),
+                (2, "08.2.txt".into(), "6".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn reuses_examples_of_part_one() {
+        let markdown = "For example:\n\n```\n1 2\n```\n\nThe sum is `*3*`.\n\n\\--- Part Two ---\n\nIn the same example:\n\n```\n1 2\n```\n\nThe product is `*2*`.\n";
+        let extraction = extract(PuzzleId::from(day!(1)), markdown);
+
+        assert_eq!(extraction.examples.len(), 1);
+        assert_eq!(
+            extraction.answers,
+            vec![
+                (1, "01.txt".into(), "3".into()),
+                (2, "01.txt".into(), "2".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn serializes_manifests() {
+        let mut manifest = Manifest::default();
+        manifest.entry("08.txt").set(1, "2");
+        manifest.entry("08.1.txt").set(2, "6");
+        manifest.entry("08.txt").set(2, "4");
+
+        let content = toml::to_string(&manifest).unwrap();
+        assert_eq!(
+            content,
+            "[[example]]\nfile = \"08.txt\"\npart_one = \"2\"\npart_two = \"4\"\n\n[[example]]\nfile = \"08.1.txt\"\npart_two = \"6\"\n"
+        );
+        assert_eq!(toml::from_str::<Manifest>(&content).unwrap(), manifest);
+    }
+}
@@ -0,0 +1,271 @@
+/// Module that keeps a local history of benchmark runs and compares them against a saved baseline.
+/// Every entry is stored as a single line of JSON.
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::protocol::{PartRecord, PartStatus};
+use crate::template::stats::BenchStats;
+use crate::{Day, Year};
+
+static HISTORY_PATH: &str = "data/benchmarks/history.jsonl";
+static BASELINE_PATH: &str = "data/benchmarks/baseline.jsonl";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// Options that control how a benchmark run is checked against the baseline.
+#[derive(Debug, Clone)]
+pub struct BaselineOptions {
+    /// Replace the baseline with the results of this run.
+    pub save: bool,
+    /// Flag parts that got slower than the baseline.
+    pub compare: bool,
+    /// Allowed slowdown in percent before a part is flagged.
+    pub threshold_percent: f64,
+}
+
+impl Default for BaselineOptions {
+    fn default() -> Self {
+        Self {
+            save: false,
+            compare: false,
+            threshold_percent: 10.0,
+        }
+    }
+}
+
+/// The benchmark result of a single part at a given commit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub commit: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub duration_nanos: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<BenchStats>,
+}
+
+impl HistoryEntry {
+    /// Creates entries for all solved parts in `records`, tagged with the current commit and time.
+    #[must_use]
+    pub fn from_records(records: &[PartRecord]) -> Vec<Self> {
+        let commit = get_commit();
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        records
+            .iter()
+            .filter(|record| record.status == PartStatus::Solved)
+            .map(|record| Self {
+                year: record.year,
+                day: record.day,
+                part: record.part,
+                commit: commit.clone(),
+                timestamp,
+                duration_nanos: record.duration_nanos,
+                stats: record.stats,
+            })
+            .collect()
+    }
+}
+
+/// The change of a single part relative to the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: Day,
+    pub part: u8,
+    pub baseline_nanos: u64,
+    pub current_nanos: u64,
+}
+
+impl Comparison {
+    /// Relative change in percent, positive values mean the part got slower.
+    #[must_use]
+    pub fn change_percent(&self) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let (baseline, current) = (self.baseline_nanos as f64, self.current_nanos as f64);
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (current - baseline) / baseline * 100.0
+    }
+
+    #[must_use]
+    pub fn is_regression(&self, threshold_percent: f64) -> bool {
+        self.change_percent() > threshold_percent
+    }
+}
+
+/// Pairs every part of the current run with its baseline entry, if there is one.
+#[must_use]
+pub fn compare(baseline: &[HistoryEntry], current: &[HistoryEntry]) -> Vec<Comparison> {
+    current
+        .iter()
+        .filter_map(|entry| {
+            let base = baseline
+                .iter()
+                .find(|b| b.year == entry.year && b.day == entry.day && b.part == entry.part)?;
+            Some(Comparison {
+                day: entry.day,
+                part: entry.part,
+                baseline_nanos: base.duration_nanos,
+                current_nanos: entry.duration_nanos,
+            })
+        })
+        .collect()
+}
+
+fn get_commit() -> String {
+    let Ok(output) = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+    else {
+        return "unknown".into();
+    };
+
+    if !output.status.success() {
+        return "unknown".into();
+    }
+
+    let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    let is_dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty());
+
+    if is_dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    }
+}
+
+fn write_entries(path: &str, entries: &[HistoryEntry], append: bool) -> Result<(), Error> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .append(append)
+        .truncate(!append)
+        .open(path)?;
+
+    for entry in entries {
+        let line = serde_json::to_string(entry).map_err(|e| Error::Parser(e.to_string()))?;
+        writeln!(file, "{line}")?;
+    }
+
+    Ok(())
+}
+
+fn read_entries(path: &str) -> Result<Vec<HistoryEntry>, Error> {
+    fs::read_to_string(path)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(|e| Error::Parser(e.to_string())))
+        .collect()
+}
+
+/// Appends the entries to the history file.
+pub fn append(entries: &[HistoryEntry]) -> Result<(), Error> {
+    write_entries(HISTORY_PATH, entries, true)
+}
+
+/// Reads every entry from the history file.
+pub fn read() -> Result<Vec<HistoryEntry>, Error> {
+    read_entries(HISTORY_PATH)
+}
+
+/// Replaces the baseline with the entries.
+pub fn save_baseline(entries: &[HistoryEntry]) -> Result<(), Error> {
+    write_entries(BASELINE_PATH, entries, false)
+}
+
+pub fn read_baseline() -> Result<Vec<HistoryEntry>, Error> {
+    read_entries(BASELINE_PATH)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, HistoryEntry};
+    use crate::template::history_entry;
+    use crate::{day, year, Day, PuzzleId};
+
+    const TIMESTAMP: u64 = 1_700_000_000;
+
+    fn puzzle(day: Day) -> PuzzleId {
+        PuzzleId::new(year!(2022), day)
+    }
+
+    #[test]
+    fn serializes_entry() {
+        let line =
+            serde_json::to_string(&history_entry(puzzle(day!(3)), 1, TIMESTAMP, 100)).unwrap();
+        assert_eq!(
+            line,
+            r#"{"year":2022,"day":3,"part":1,"commit":"abc1234","timestamp":1700000000,"duration_nanos":100}"#
+        );
+    }
+
+    #[test]
+    fn requires_year() {
+        let entry = serde_json::from_str::<HistoryEntry>(
+            r#"{"day":3,"part":1,"commit":"abc1234","timestamp":1700000000,"duration_nanos":100}"#,
+        );
+        assert!(entry.is_err());
+    }
+
+    #[test]
+    fn compares_matching_parts() {
+        let other_year = HistoryEntry {
+            year: year!(2021),
+            ..history_entry(puzzle(day!(3)), 1, TIMESTAMP, 10)
+        };
+        let baseline = vec![
+            history_entry(puzzle(day!(1)), 1, TIMESTAMP, 100),
+            history_entry(puzzle(day!(1)), 2, TIMESTAMP, 200),
+            history_entry(puzzle(day!(2)), 1, TIMESTAMP, 100),
+            other_year,
+        ];
+        let current = vec![
+            history_entry(puzzle(day!(1)), 1, TIMESTAMP, 120),
+            history_entry(puzzle(day!(1)), 2, TIMESTAMP, 190),
+            history_entry(puzzle(day!(3)), 1, TIMESTAMP, 50),
+        ];
+
+        let comparisons = compare(&baseline, &current);
+        assert_eq!(comparisons.len(), 2);
+
+        assert_eq!(comparisons[0].day, day!(1));
+        assert_eq!(comparisons[0].part, 1);
+        assert!((comparisons[0].change_percent() - 20.0).abs() < 1e-9);
+        assert!(comparisons[0].is_regression(10.0));
+        assert!(!comparisons[0].is_regression(25.0));
+
+        assert!((comparisons[1].change_percent() + 5.0).abs() < 1e-9);
+        assert!(!comparisons[1].is_regression(0.0));
+    }
+}
@@ -0,0 +1,409 @@
+/// Module that checks input and example files for formatting problems that trip up parsers,
+/// and normalizes them if `AOC_NORMALIZE_INPUT` is set.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::template::get_data_path;
+use crate::{PuzzleId, Year};
+
+/// A formatting problem of an input or example file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// The file is empty, e.g. because it was scaffolded but never downloaded.
+    Empty,
+    /// Lines end with `\r\n`, counts the lines.
+    CrLf(usize),
+    /// The last line does not end with a newline.
+    MissingTrailingNewline,
+    /// The file ends with blank lines, counts them.
+    ExtraTrailingNewlines(usize),
+    /// Lines end with spaces or tabs, lists their numbers.
+    TrailingWhitespace(Vec<usize>),
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::Empty => f.write_str("file is empty"),
+            Problem::CrLf(count) => write!(f, "{count} line(s) end with CRLF"),
+            Problem::MissingTrailingNewline => {
+                f.write_str("missing newline at the end of the file")
+            }
+            Problem::ExtraTrailingNewlines(count) => {
+                write!(f, "{count} blank line(s) at the end of the file")
+            }
+            Problem::TrailingWhitespace(lines) => {
+                let shown: Vec<String> = lines.iter().take(5).map(ToString::to_string).collect();
+                let more = if lines.len() > 5 { ", ..." } else { "" };
+                write!(
+                    f,
+                    "trailing whitespace on line(s) {}{more}",
+                    shown.join(", ")
+                )
+            }
+        }
+    }
+}
+
+/// Returns every formatting problem of the contents of an input or example file.
+#[must_use]
+pub fn lint(contents: &str) -> Vec<Problem> {
+    if contents.trim().is_empty() {
+        return vec![Problem::Empty];
+    }
+
+    let mut problems = vec![];
+
+    let crlf = contents.matches("\r\n").count();
+    if crlf > 0 {
+        problems.push(Problem::CrLf(crlf));
+    }
+
+    let contents = contents.replace("\r\n", "\n");
+    if !contents.ends_with('\n') {
+        problems.push(Problem::MissingTrailingNewline);
+    }
+
+    let blank_lines = contents.len() - contents.trim_end_matches('\n').len();
+    if blank_lines > 1 {
+        problems.push(Problem::ExtraTrailingNewlines(blank_lines - 1));
+    }
+
+    let whitespace: Vec<usize> = contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.ends_with([' ', '\t']))
+        .map(|(i, _)| i + 1)
+        .collect();
+    if !whitespace.is_empty() {
+        problems.push(Problem::TrailingWhitespace(whitespace));
+    }
+
+    problems
+}
+
+/// Fixes the problems reported by [`lint`], except for empty files:
+/// converts CRLF to LF, strips trailing whitespace and ends the file with exactly one newline.
+#[must_use]
+pub fn normalize(contents: &str) -> String {
+    let mut normalized: String = contents
+        .lines()
+        .map(|line| line.trim_end_matches([' ', '\t', '\r']))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end_matches('\n')
+        .to_string();
+
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// Whether `read_file` normalizes files, opt in with `AOC_NORMALIZE_INPUT = "true"` in `.cargo/config.toml`.
+#[must_use]
+pub fn is_normalizing() -> bool {
+    env::var("AOC_NORMALIZE_INPUT").is_ok_and(|value| value == "true" || value == "1")
+}
+
+/// Reads an input or example file, normalized if [`is_normalizing`].
+pub fn read(path: &Path) -> io::Result<String> {
+    let contents = fs::read_to_string(path)?;
+    if is_normalizing() {
+        Ok(normalize(&contents))
+    } else {
+        Ok(contents)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when reading the input of a puzzle.
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file is empty or does not exist.
+    Missing(PuzzleId),
+    /// The example file is empty or does not exist.
+    MissingExample(PuzzleId, PathBuf),
+    IO(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing(puzzle) => {
+                write!(f, "input missing, run `cargo download {puzzle}`")
+            }
+            InputError::MissingExample(puzzle, path) => write!(
+                f,
+                "example \"{}\" missing, run `cargo examples {puzzle}`",
+                path.display()
+            ),
+            InputError::IO(e) => write!(f, "could not read input: {e}"),
+        }
+    }
+}
+
+/// Reads the input of a puzzle, reporting inputs that were not downloaded yet as [`InputError::Missing`].
+pub fn read_input(puzzle: PuzzleId) -> Result<String, InputError> {
+    let path = get_data_path("inputs", puzzle, &format!("{}.txt", puzzle.day));
+
+    match read(&path) {
+        Ok(input) if input.trim().is_empty() => Err(InputError::Missing(puzzle)),
+        Ok(input) => Ok(input),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(InputError::Missing(puzzle)),
+        Err(e) => Err(InputError::IO(e)),
+    }
+}
+
+/// Where a solution reads its input from.
+/// Solutions run against the puzzle input, unless told otherwise with `--input <path>`, `--input -` or `--example [trial]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input, e.g. `data/inputs/12.txt`.
+    #[default]
+    Puzzle,
+    /// Any file, e.g. a hand-crafted edge case.
+    File(PathBuf),
+    /// The standard input of the process.
+    Stdin,
+    /// An example of the puzzle, `data/examples/12.txt` or, with a trial, `data/examples/12.<trial>.txt`.
+    Example(Option<String>),
+}
+
+impl InputSource {
+    /// Reads the source from the arguments passed to a solution binary.
+    #[must_use]
+    pub fn from_args(args: &[String]) -> Self {
+        let value_of = |name: &str| {
+            let i = args.iter().position(|x| x == name)?;
+            Some(args.get(i + 1).filter(|value| !value.starts_with("--")))
+        };
+
+        if let Some(value) = value_of("--input") {
+            match value.map(String::as_str) {
+                Some("-") => InputSource::Stdin,
+                Some(path) => InputSource::File(PathBuf::from(path)),
+                None => InputSource::Puzzle,
+            }
+        } else if let Some(trial) = value_of("--example") {
+            InputSource::Example(trial.cloned())
+        } else {
+            InputSource::Puzzle
+        }
+    }
+
+    /// The arguments that pass the source on to a solution binary, the inverse of [`InputSource::from_args`].
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::File(path) => vec!["--input".into(), path.display().to_string()],
+            InputSource::Stdin => vec!["--input".into(), "-".into()],
+            InputSource::Example(None) => vec!["--example".into()],
+            InputSource::Example(Some(trial)) => vec!["--example".into(), trial.clone()],
+        }
+    }
+
+    /// Whether the source is the puzzle input. Answers for any other input must never be submitted.
+    #[must_use]
+    pub fn is_canonical(&self) -> bool {
+        *self == InputSource::Puzzle
+    }
+
+    /// Reads the input of a puzzle from the source.
+    pub fn read(&self, puzzle: PuzzleId) -> Result<String, InputError> {
+        match self {
+            InputSource::Puzzle => read_input(puzzle),
+            InputSource::File(path) => read(path).map_err(InputError::IO),
+            InputSource::Stdin => {
+                let mut contents = String::new();
+                io::stdin()
+                    .read_to_string(&mut contents)
+                    .map_err(InputError::IO)?;
+                if is_normalizing() {
+                    contents = normalize(&contents);
+                }
+                Ok(contents)
+            }
+            InputSource::Example(trial) => {
+                let file_name = match trial {
+                    Some(trial) => format!("{}.{trial}.txt", puzzle.day),
+                    None => format!("{}.txt", puzzle.day),
+                };
+                let path = get_data_path("examples", puzzle, &file_name);
+                match read(&path) {
+                    Ok(input) if !input.trim().is_empty() => Ok(input),
+                    Err(e) if e.kind() != io::ErrorKind::NotFound => Err(InputError::IO(e)),
+                    _ => Err(InputError::MissingExample(puzzle, path)),
+                }
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Puzzle => f.write_str("the puzzle input"),
+            InputSource::File(path) => write!(f, "\"{}\"", path.display()),
+            InputSource::Stdin => f.write_str("stdin"),
+            InputSource::Example(None) => f.write_str("the example"),
+            InputSource::Example(Some(trial)) => write!(f, "example {trial}"),
+        }
+    }
+}
+
+/// Lists the `.txt` files of the input and example folders of every year below `data_dir`.
+pub fn find_files(data_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut folders = vec![data_dir.to_path_buf()];
+    for entry in fs::read_dir(data_dir)? {
+        let path = entry?.path();
+        let is_year = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.parse::<Year>().is_ok());
+        if path.is_dir() && is_year {
+            folders.push(path);
+        }
+    }
+
+    let mut files = vec![];
+    for folder in folders {
+        for kind in ["inputs", "examples"] {
+            let Ok(entries) = fs::read_dir(folder.join(kind)) else {
+                continue;
+            };
+            for entry in entries {
+                let path = entry?.path();
+                if path.extension().is_some_and(|ext| ext == "txt") {
+                    files.push(path);
+                }
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use std::path::PathBuf;
+
+    use super::{find_files, lint, normalize, InputSource, Problem};
+    use crate::template::fixture_dir;
+
+    #[test]
+    fn accepts_clean_files() {
+        assert_eq!(lint("1 2\n3 4\n"), vec![]);
+        assert_eq!(lint("  1\n 2\n"), vec![]);
+    }
+
+    #[test]
+    fn reports_problems() {
+        assert_eq!(lint(""), vec![Problem::Empty]);
+        assert_eq!(lint("\n\n"), vec![Problem::Empty]);
+        assert_eq!(lint("1\r\n2\r\n"), vec![Problem::CrLf(2)]);
+        assert_eq!(lint("1\n2"), vec![Problem::MissingTrailingNewline]);
+        assert_eq!(lint("1\n2\n\n\n"), vec![Problem::ExtraTrailingNewlines(2)]);
+        assert_eq!(
+            lint("1 \n2\n3\t\n"),
+            vec![Problem::TrailingWhitespace(vec![1, 3])]
+        );
+        assert_eq!(
+            lint("1 \r\n2"),
+            vec![
+                Problem::CrLf(1),
+                Problem::MissingTrailingNewline,
+                Problem::TrailingWhitespace(vec![1])
+            ]
+        );
+    }
+
+    #[test]
+    fn normalizes_files() {
+        assert_eq!(normalize("1 \r\n 2\t\r\n\r\n\r\n"), "1\n 2\n");
+        assert_eq!(normalize("1\n\n2"), "1\n\n2\n");
+        assert_eq!(normalize(""), "");
+        assert_eq!(lint(&normalize("1 \r\n2\n\n")), vec![]);
+    }
+
+    #[test]
+    fn finds_files_of_every_year() {
+        let data = fixture_dir("input_files");
+        for folder in [
+            "inputs",
+            "examples",
+            "puzzles",
+            "2022/inputs",
+            "notes/inputs",
+        ] {
+            fs::create_dir_all(data.join(folder)).unwrap();
+        }
+        for file in [
+            "inputs/01.txt",
+            "examples/01.1.txt",
+            "puzzles/01.md",
+            "2022/inputs/03.txt",
+            "notes/inputs/01.txt",
+        ] {
+            fs::write(data.join(file), "").unwrap();
+        }
+
+        let files: Vec<String> = find_files(&data)
+            .unwrap()
+            .iter()
+            .map(|path| path.strip_prefix(&data).unwrap().display().to_string())
+            .collect();
+        assert_eq!(
+            files,
+            vec!["2022/inputs/03.txt", "examples/01.1.txt", "inputs/01.txt"]
+        );
+    }
+
+    #[test]
+    fn reads_sources_from_args() {
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+            InputSource::from_args(&args)
+        };
+
+        assert_eq!(parse(&["05", "--time"]), InputSource::Puzzle);
+        assert_eq!(
+            parse(&["05", "--input", "edge.txt", "--time"]),
+            InputSource::File(PathBuf::from("edge.txt"))
+        );
+        assert_eq!(parse(&["05", "--input", "-"]), InputSource::Stdin);
+        assert_eq!(parse(&["05", "--example"]), InputSource::Example(None));
+        assert_eq!(
+            parse(&["05", "--example", "--time"]),
+            InputSource::Example(None)
+        );
+        assert_eq!(
+            parse(&["05", "--example", "2"]),
+            InputSource::Example(Some("2".into()))
+        );
+    }
+
+    #[test]
+    fn passes_sources_as_args() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::File(PathBuf::from("edge.txt")),
+            InputSource::Stdin,
+            InputSource::Example(None),
+            InputSource::Example(Some("1".into())),
+        ] {
+            assert_eq!(InputSource::from_args(&source.to_args()), source);
+        }
+        assert!(InputSource::Puzzle.is_canonical());
+        assert!(!InputSource::Example(None).is_canonical());
+    }
+}
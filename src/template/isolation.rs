@@ -0,0 +1,193 @@
+/// Module that runs parts in child processes of the main binary, so a part that never finishes can be killed
+/// and a part that aborts its process, e.g. with a stack overflow, does not take the whole run down with it.
+use std::{
+    env,
+    io::Read,
+    process::{Child, Command, ExitStatus, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::template::protocol::{PartRecord, PartStatus};
+use crate::template::runner::RunOptions;
+use crate::PuzzleId;
+
+/// The hidden command of the main binary that runs a single part and prints its record as JSON.
+pub const RUN_PART_COMMAND: &str = "run-part";
+
+/// How often the parent checks whether the child exited.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// The outcome of a part that ran in a child process.
+pub struct IsolatedRun {
+    pub record: PartRecord,
+    /// Lines the part printed besides its record, e.g. debug output of the solution.
+    pub output: Vec<String>,
+}
+
+/// Runs a part against its puzzle input in a child process and kills it once it runs longer than `timeout`.
+/// With `--time`, `timeout` limits a single execution, the benchmark of the part gets as long as it needs for that,
+/// see [`BenchOptions::max_duration`](crate::template::runner::BenchOptions::max_duration).
+pub fn run_part(
+    puzzle: PuzzleId,
+    part: u8,
+    options: &RunOptions,
+    timeout: Duration,
+) -> IsolatedRun {
+    let crashed = |error: String| IsolatedRun {
+        record: PartRecord {
+            status: PartStatus::Panicked,
+            error: Some(error),
+            ..PartRecord::unsolved(puzzle, part)
+        },
+        output: vec![],
+    };
+
+    let child = env::current_exe().and_then(|exe| {
+        Command::new(exe)
+            .args(get_args(puzzle, part, options))
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+    });
+
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => return crashed(format!("could not start a process for the part: {e}")),
+    };
+
+    // drained while the part runs, so a part that prints a lot can not block on a full pipe.
+    let mut stdout = child.stdout.take();
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        if let Some(stdout) = stdout.as_mut() {
+            let _ = stdout.read_to_string(&mut output);
+        }
+        output
+    });
+
+    let status = wait_with_timeout(&mut child, get_deadline(options, timeout));
+    let stdout = reader.join().unwrap_or_default();
+    let (record, output) = parse_output(&stdout, puzzle, part);
+
+    match (status, record) {
+        (None, _) => IsolatedRun {
+            record: PartRecord {
+                status: PartStatus::TimedOut,
+                #[allow(clippy::cast_possible_truncation)]
+                duration_nanos: timeout.as_nanos() as u64,
+                ..PartRecord::unsolved(puzzle, part)
+            },
+            output,
+        },
+        (Some(_), Some(record)) => IsolatedRun { record, output },
+        (Some(status), None) => IsolatedRun {
+            output,
+            ..crashed(format!("the process of the part exited with {status}"))
+        },
+    }
+}
+
+/// How long the child may run: with `--time`, the limit applies to every execution of the part.
+fn get_deadline(options: &RunOptions, timeout: Duration) -> Duration {
+    if options.is_timed {
+        options.bench.max_duration(timeout)
+    } else {
+        timeout
+    }
+}
+
+/// Waits for the child to exit. Kills it and returns [`None`] if it runs longer than `timeout`.
+fn wait_with_timeout(child: &mut Child, timeout: Duration) -> Option<ExitStatus> {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return Some(status),
+            Ok(None) if Instant::now() < deadline => thread::sleep(POLL_INTERVAL),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+        }
+    }
+}
+
+fn get_args(puzzle: PuzzleId, part: u8, options: &RunOptions) -> Vec<String> {
+    let mut args = vec![
+        RUN_PART_COMMAND.to_string(),
+        puzzle.to_string(),
+        part.to_string(),
+    ];
+
+    if options.is_timed {
+        args.push("--time".to_string());
+        args.push("--warmup".to_string());
+        args.push(options.bench.warmup.to_string());
+        args.push("--budget".to_string());
+        args.push(options.bench.budget.as_millis().to_string());
+    }
+
+    if options.count_allocs {
+        args.push("--alloc".to_string());
+    }
+
+    args
+}
+
+/// Splits the output of the child into the record of the part and the lines printed besides it.
+fn parse_output(stdout: &str, puzzle: PuzzleId, part: u8) -> (Option<PartRecord>, Vec<String>) {
+    let mut record = None;
+    let mut output = vec![];
+
+    for line in stdout.lines() {
+        match serde_json::from_str::<PartRecord>(line) {
+            Ok(parsed) if parsed.puzzle() == puzzle && parsed.part == part => record = Some(parsed),
+            _ => output.push(line.to_string()),
+        }
+    }
+
+    (record, output)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{get_deadline, parse_output};
+    use crate::day;
+    use crate::template::protocol::{PartRecord, PartStatus};
+    use crate::template::runner::RunOptions;
+
+    #[test]
+    fn extends_deadline_of_timed_runs() {
+        let timeout = Duration::from_secs(5);
+        let mut options = RunOptions::default();
+        assert_eq!(get_deadline(&options, timeout), timeout);
+
+        // the first execution, 3 warmups and 10 samples, plus the budget of 1 second.
+        options.is_timed = true;
+        assert_eq!(get_deadline(&options, timeout), Duration::from_secs(71));
+    }
+
+    #[test]
+    fn separates_records_from_output() {
+        let record = PartRecord {
+            answer: Some("42".into()),
+            status: PartStatus::Solved,
+            ..PartRecord::unsolved(day!(3).into(), 2)
+        };
+        let other = PartRecord::unsolved(day!(4).into(), 2);
+        let stdout = format!("count: 1\n{}\n{}\n", other.to_json(), record.to_json());
+
+        let (parsed, output) = parse_output(&stdout, day!(3).into(), 2);
+        assert_eq!(parsed, Some(record));
+        assert_eq!(output, vec!["count: 1".to_string(), other.to_json()]);
+
+        let (parsed, output) = parse_output("count: 1\n", day!(3).into(), 1);
+        assert_eq!(parsed, None);
+        assert_eq!(output, vec!["count: 1".to_string()]);
+    }
+}
@@ -1,48 +1,192 @@
-use crate::Day;
-use std::{env, fs};
+use crate::PuzzleId;
+use std::{
+    env, io,
+    path::{Path, PathBuf},
+};
 
+pub mod alloc;
+pub mod answers;
 pub mod aoc_cli;
+pub mod cache;
 pub mod commands;
+mod error;
+pub mod examples;
+pub mod history;
+pub mod input;
+pub mod isolation;
+pub mod protocol;
 pub mod readme_benchmarks;
+pub mod readme_stars;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod service;
+pub mod stats;
+pub mod submission;
+
+pub use error::SolutionError;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// Creates an empty directory for the files of a test, unique to the test and the process.
+/// Public, so the integration tests in `tests` share it with the tests of the library.
+#[must_use]
+pub fn fixture_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-fixtures-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Writes a file of a test, creating its parent directories.
+pub fn write_fixture(path: impl AsRef<Path>, contents: &str) {
+    let path = path.as_ref();
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+/// Builds the record of a part of day 1 of the home year for a test.
+#[cfg(feature = "test_lib")]
+pub(crate) fn part_record(
+    part: u8,
+    answer: Option<&str>,
+    duration_nanos: u64,
+    status: protocol::PartStatus,
+) -> protocol::PartRecord {
+    protocol::PartRecord {
+        answer: answer.map(Into::into),
+        duration_nanos,
+        status,
+        ..protocol::PartRecord::unsolved(crate::day!(1).into(), part)
+    }
+}
+
+/// Builds a benchmark history entry for a test.
+#[cfg(feature = "test_lib")]
+pub(crate) fn history_entry(
+    puzzle: PuzzleId,
+    part: u8,
+    timestamp: u64,
+    duration_nanos: u64,
+) -> history::HistoryEntry {
+    history::HistoryEntry {
+        year: puzzle.year,
+        day: puzzle.day,
+        part,
+        commit: "abc1234".into(),
+        timestamp,
+        duration_nanos,
+        stats: None,
+    }
+}
+
+/// Returns the path of a puzzle's file in `data`, e.g. `data/2022/inputs/12.txt`.
+#[must_use]
+pub fn get_data_path(folder: &str, puzzle: PuzzleId, file_name: &str) -> PathBuf {
+    Path::new("data")
+        .join(puzzle.data_folder(folder))
+        .join(file_name)
+}
 
 /// Helper function that reads a text file to a string.
+/// The contents are normalized if `AOC_NORMALIZE_INPUT` is set, see [`input::normalize`].
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, puzzle: impl Into<PuzzleId>) -> String {
+    try_read_file(folder, puzzle).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to a string, returning an error if the file can not be read.
+pub fn try_read_file(folder: &str, puzzle: impl Into<PuzzleId>) -> io::Result<String> {
+    let puzzle = puzzle.into();
+    let cwd = env::current_dir()?;
+    let filepath = cwd.join(get_data_path(
+        folder,
+        puzzle,
+        &format!("{}.txt", puzzle.day),
+    ));
+    input::read(&filepath)
 }
 
 /// Helper function that reads a text file for a trial to a string.
 #[must_use]
-pub fn read_file_for_trial(folder: &str, day: Day, trial: &str) -> String {
+pub fn read_file_for_trial(folder: &str, puzzle: impl Into<PuzzleId>, trial: &str) -> String {
+    let puzzle = puzzle.into();
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}.{trial}.txt"));
-    let f = fs::read_to_string(filepath);
+    let filepath = cwd.join(get_data_path(
+        folder,
+        puzzle,
+        &format!("{}.{trial}.txt", puzzle.day),
+    ));
+    let f = input::read(&filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR`, `DAY` and `PUZZLE` and sets up the input and runner for each part.
+/// Also exposes the parts as a [`registry::Solution`], so the main binary can run them in-process.
+///
+/// `solution!(12)` solves a puzzle of the year configured with `AOC_YEAR`, `solution!(2022, 12)` one of another year.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    (@puzzle $year:expr, $day:expr) => {
+        /// The current year.
+        #[allow(dead_code)]
+        const YEAR: advent_of_code::Year = $year;
+
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
+        /// The current puzzle, use this to read files of puzzles that are not from the home year.
+        #[allow(dead_code)]
+        const PUZZLE: advent_of_code::PuzzleId = advent_of_code::PuzzleId::new(YEAR, DAY);
+
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let options = RunOptions::from_args();
+            let input = options.input.read(PUZZLE).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            run_part(part_one, &input, PUZZLE, 1, &options);
+            run_part(part_two, &input, PUZZLE, 2, &options);
+        }
+
+        /// The parts of the current puzzle, for registration with the in-process registry.
+        #[allow(dead_code)]
+        pub fn solution() -> advent_of_code::template::registry::Solution {
+            advent_of_code::template::registry::Solution::new(PUZZLE, part_one, part_two)
+        }
+    };
+    ($day:expr) => {
+        advent_of_code::solution!(@puzzle advent_of_code::Year::home(), $day);
+    };
+    ($year:expr, $day:expr) => {
+        advent_of_code::solution!(@puzzle advent_of_code::year!($year), $day);
+    };
+}
+
+/// Generates a test for every expected answer in the puzzle's example manifest, `data/examples/NN.toml`.
+///
+/// ```toml
+/// [[example]]
+/// file = "08.txt"
+/// part_one = "6"
+/// ```
+///
+/// The tests are generated by the build script, a new case only needs an entry in the manifest.
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        #[cfg(test)]
+        mod examples {
+            include!(concat!(
+                env!("OUT_DIR"),
+                "/examples/",
+                env!("CARGO_BIN_NAME"),
+                ".rs"
+            ));
         }
     };
 }
@@ -0,0 +1,189 @@
+/// Machine-readable result protocol shared by the solution binaries and the `all` command.
+/// With `--format json`, every part is emitted as a single JSON line on stdout.
+/// Solutions may print to stdout as well, so readers skip lines that are not records.
+use std::{error::Error, fmt::Display, str::FromStr, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::alloc::AllocStats;
+use crate::template::stats::BenchStats;
+use crate::template::submission::Verdict;
+use crate::{Day, PuzzleId, Year};
+
+/// The format in which the runner reports results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text => f.write_str("text"),
+            Self::Json => f.write_str("json"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an output format of either \"text\" or \"json\"")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The outcome of running a single part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PartStatus {
+    Solved,
+    Unsolved,
+    /// The part returned a [`SolutionError`](crate::template::SolutionError).
+    Failed,
+    Panicked,
+    /// The part was killed after running longer than the timeout of `all`.
+    #[serde(rename = "timed_out")]
+    TimedOut,
+}
+
+/// A structured record of a single part's run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartRecord {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration_nanos: u64,
+    pub samples: u64,
+    pub status: PartStatus,
+    /// Benchmark statistics, only present for timed runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<BenchStats>,
+    /// Allocation counts, only present for runs with `--alloc`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alloc: Option<AllocStats>,
+    /// The verdict of the puzzle website, only present if the part was submitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<Verdict>,
+    /// Why the part failed, only present for failed parts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl PartRecord {
+    /// Creates a record for a part that was never run, e.g. because the day has not been scaffolded yet.
+    #[must_use]
+    pub fn unsolved(puzzle: PuzzleId, part: u8) -> Self {
+        Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            part,
+            answer: None,
+            duration_nanos: 0,
+            samples: 0,
+            status: PartStatus::Unsolved,
+            stats: None,
+            alloc: None,
+            verdict: None,
+            error: None,
+        }
+    }
+
+    #[must_use]
+    pub fn puzzle(&self) -> PuzzleId {
+        PuzzleId::new(self.year, self.day)
+    }
+
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.duration_nanos)
+    }
+
+    /// Serializes the record as a single line of JSON.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        // serializing a struct of plain values into a string can not fail.
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// Parses a record from a line of output, returns [`None`] if the line is not a record.
+    #[must_use]
+    pub fn from_json(line: &str) -> Option<Self> {
+        serde_json::from_str(line.trim()).ok()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{OutputFormat, PartRecord, PartStatus};
+    use crate::template::submission::Verdict;
+    use crate::{day, year};
+
+    #[test]
+    fn parses_output_format() {
+        assert_eq!("text".parse::<OutputFormat>().unwrap(), OutputFormat::Text);
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn serializes_record() {
+        let record = PartRecord {
+            year: year!(2022),
+            day: day!(1),
+            part: 2,
+            answer: Some("42".into()),
+            duration_nanos: 1500,
+            samples: 10,
+            status: PartStatus::Solved,
+            stats: None,
+            alloc: None,
+            verdict: None,
+            error: None,
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2022,"day":1,"part":2,"answer":"42","duration_nanos":1500,"samples":10,"status":"solved"}"#
+        );
+        assert_eq!(PartRecord::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn serializes_verdict() {
+        let record = PartRecord {
+            answer: Some("42".into()),
+            status: PartStatus::Solved,
+            verdict: Some(Verdict::TooHigh),
+            ..PartRecord::unsolved(day!(1).into(), 1)
+        };
+        assert!(record.to_json().ends_with(r#""verdict":"too_high"}"#));
+        assert_eq!(PartRecord::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn ignores_non_record_lines() {
+        assert_eq!(PartRecord::from_json("Part 1: 42 (1.0ms)"), None);
+        assert_eq!(PartRecord::from_json(r#"{"day":26,"part":1}"#), None);
+    }
+}
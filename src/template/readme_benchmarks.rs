@@ -2,10 +2,14 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::get_data_path;
+use crate::{PuzzleId, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
+/// Number of characters of the relative cost bar of a day.
+const BAR_WIDTH: usize = 10;
+
 #[derive(Debug)]
 pub enum Error {
     Parser(String),
@@ -20,24 +24,85 @@ impl From<std::io::Error> for Error {
 
 #[derive(Clone)]
 pub struct Timings {
-    pub day: Day,
+    pub puzzle: PuzzleId,
+    /// The title of the puzzle, see [`read_title`].
+    pub title: Option<String>,
+    /// Whether the parts returned an answer.
+    pub part_1_solved: bool,
+    pub part_2_solved: bool,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    /// Allocation counts of the parts, only present for runs with `--alloc`.
+    pub part_1_alloc: Option<String>,
+    pub part_2_alloc: Option<String>,
     pub total_nanos: f64,
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
+}
+
+#[must_use]
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{}.rs", puzzle.bin_name())
 }
 
+/// Reads the title of a puzzle from its downloaded description, e.g. `Trebuchet?!` for day 1 of 2023.
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn read_title(puzzle: PuzzleId) -> Option<String> {
+    let description = fs::read_to_string(get_data_path(
+        "puzzles",
+        puzzle,
+        &format!("{}.md", puzzle.day),
+    ))
+    .ok()?;
+    parse_title(&description)
+}
+
+/// Parses the title from the heading of a description, `\--- Day 1: Trebuchet?! ---`.
+fn parse_title(description: &str) -> Option<String> {
+    let heading = description.lines().find(|line| line.contains("--- Day "))?;
+    let (_, title) = heading.split_once(": ")?;
+    let title = title.trim().trim_end_matches('-').trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// A bar of the cost of a day relative to the slowest day, e.g. `███░░░░░░░`.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn cost_bar(nanos: f64, max_nanos: f64) -> String {
+    let filled = if max_nanos > 0.0 {
+        // rounded up, so every day that took any time shows up.
+        ((nanos / max_nanos * BAR_WIDTH as f64).ceil() as usize).min(BAR_WIDTH)
+    } else {
+        0
+    };
+    format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled))
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn status_icon(is_solved: bool) -> &'static str {
+    if is_solved {
+        "✔"
+    } else {
+        "✖"
+    }
+}
+
+/// The home year uses the plain marker, other years have their own table, e.g. `<!--- benchmarking table 2022 --->`.
+fn get_marker(year: Year) -> String {
+    if year == Year::home() {
+        MARKER.into()
+    } else {
+        format!("<!--- benchmarking table {year} --->")
+    }
+}
+
+pub(crate) fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -45,10 +110,11 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
         ));
     }
 
-    let pos_start = matches
-        .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+    let pos_start = matches.first().map(|m| m.0).ok_or_else(|| {
+        Error::Parser(format!(
+            "Could not find table start position, add two `{marker}` lines to the README."
+        ))
+    })?;
 
     let pos_end = matches
         .last()
@@ -58,73 +124,124 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(prefix: &str, year: Year, timings: Vec<Timings>, total_millis: f64) -> String {
+    let marker = get_marker(year);
+    let header = if year == Year::home() {
+        format!("{prefix} Benchmarks")
+    } else {
+        format!("{prefix} {year} Benchmarks")
+    };
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // the allocations column is only shown for runs with `--alloc`.
+    let has_allocs = timings
+        .iter()
+        .any(|timing| timing.part_1_alloc.is_some() || timing.part_2_alloc.is_some());
+
+    let mut lines: Vec<String> = vec![marker.clone(), header, String::new()];
+
+    if has_allocs {
+        lines.push("| Day | Title | Part 1 | Part 2 | Cost | Allocations (count · peak) |".into());
+        lines.push("| :---: | :--- | :---: | :---:  | :--- | :---: |".into());
+    } else {
+        lines.push("| Day | Title | Part 1 | Part 2 | Cost |".into());
+        lines.push("| :---: | :--- | :---: | :---:  | :--- |".into());
+    }
+
+    let max_nanos = timings
+        .iter()
+        .map(|timing| timing.total_nanos)
+        .fold(0_f64, f64::max);
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+        let path = get_path_for_bin(timing.puzzle);
+        let mut line = format!(
+            "| [Day {}]({}) | {} | {} `{}` | {} `{}` | `{}` |",
+            timing.puzzle.day.into_inner(),
             path,
+            timing
+                .title
+                .map_or_else(|| "-".into(), |title| title.replace('|', "\\|")),
+            status_icon(timing.part_1_solved),
             timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+            status_icon(timing.part_2_solved),
+            timing.part_2.unwrap_or_else(|| "-".into()),
+            cost_bar(timing.total_nanos, max_nanos),
+        );
+        if has_allocs {
+            line.push_str(&format!(
+                " `{}` / `{}` |",
+                timing.part_1_alloc.unwrap_or_else(|| "-".into()),
+                timing.part_2_alloc.unwrap_or_else(|| "-".into())
+            ));
+        }
+        lines.push(line);
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Vec<Timings>,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let positions = locate_table(s, &get_marker(year))?;
+    let table = construct_table("##", year, timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+pub fn update(year: Year, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use super::{cost_bar, parse_title, update_content, Timings, MARKER};
+    use crate::{day, year, PuzzleId, Year};
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
-                day: day!(1),
+                puzzle: day!(1).into(),
+                title: None,
+                part_1_solved: true,
+                part_2_solved: true,
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
+                part_1_alloc: None,
+                part_2_alloc: None,
                 total_nanos: 3e+10,
             },
             Timings {
-                day: day!(2),
+                puzzle: day!(2).into(),
+                title: None,
+                part_1_solved: true,
+                part_2_solved: true,
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
+                part_1_alloc: None,
+                part_2_alloc: None,
                 total_nanos: 7e+10,
             },
             Timings {
-                day: day!(4),
+                puzzle: day!(4).into(),
+                title: None,
+                part_1_solved: true,
+                part_2_solved: true,
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
+                part_1_alloc: None,
+                part_2_alloc: None,
                 total_nanos: 9e+10,
             },
         ]
@@ -134,28 +251,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, Year::home(), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, Year::home(), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, Year::home(), get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, Year::home(), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, Year::home(), get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,18 +280,22 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let mut timings = get_mock_timings();
+        timings[0].title = Some("Trebuchet?!".into());
+        timings[1].part_2 = None;
+        timings[1].part_2_solved = false;
+        update_content(&mut s, Year::home(), timings, 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Title | Part 1 | Part 2 | Cost |",
+            "| :---: | :--- | :---: | :---:  | :--- |",
+            "| [Day 1](./src/bin/01.rs) | Trebuchet?! | ✔ `10ms` | ✔ `20ms` | `████░░░░░░` |",
+            "| [Day 2](./src/bin/02.rs) | - | ✔ `30ms` | ✖ `-` | `████████░░` |",
+            "| [Day 4](./src/bin/04.rs) | - | ✔ `40ms` | ✔ `50ms` | `██████████` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +304,56 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn updates_table_of_other_year() {
+        let marker = "<!--- benchmarking table 2015 --->";
+        let mut s = format!("{MARKER}{MARKER}\n{marker}\n{marker}");
+        let timings = vec![Timings {
+            puzzle: PuzzleId::new(year!(2015), day!(3)),
+            title: None,
+            part_1_solved: true,
+            part_2_solved: true,
+            part_1: Some("1ms".into()),
+            part_2: None,
+            part_1_alloc: None,
+            part_2_alloc: None,
+            total_nanos: 1e+6,
+        }];
+        update_content(&mut s, year!(2015), timings, 1.0).unwrap();
+        assert!(s.starts_with(&format!("{MARKER}{MARKER}\n{marker}\n## 2015 Benchmarks")));
+        assert!(
+            s.contains("| [Day 3](./src/bin/2015_03.rs) | - | ✔ `1ms` | ✔ `-` | `██████████` |")
+        );
+    }
+
+    #[test]
+    fn shows_allocations() {
+        let mut s = format!("{MARKER}\n{MARKER}");
+        let mut timings = get_mock_timings();
+        timings[0].part_1_alloc = Some("12 · 1.0 KiB".into());
+        update_content(&mut s, Year::home(), timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Title | Part 1 | Part 2 | Cost | Allocations (count · peak) |"));
+        assert!(s.contains("| ✔ `20ms` | `████░░░░░░` | `12 · 1.0 KiB` / `-` |"));
+        assert!(s.contains("| ✔ `40ms` | `████████░░` | `-` / `-` |"));
+    }
+
+    #[test]
+    fn parses_titles() {
+        let description = "\\--- Day 1: Trebuchet?! ---\n----------\n\nSomething is wrong";
+        assert_eq!(parse_title(description), Some("Trebuchet?!".into()));
+        assert_eq!(
+            parse_title("## --- Day 20: Pulse Propagation ---"),
+            Some("Pulse Propagation".into())
+        );
+        assert_eq!(parse_title("Something is wrong"), None);
+    }
+
+    #[test]
+    fn draws_cost_bars() {
+        assert_eq!(cost_bar(10.0, 10.0), "██████████");
+        assert_eq!(cost_bar(0.1, 10.0), "█░░░░░░░░░");
+        assert_eq!(cost_bar(0.0, 10.0), "░░░░░░░░░░");
+        assert_eq!(cost_bar(0.0, 0.0), "░░░░░░░░░░");
+    }
 }
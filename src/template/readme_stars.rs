@@ -0,0 +1,162 @@
+/// Module that fills the stars table of the readme from the accepted answers and submission logs.
+/// The table looks like the one of `aoc-readme-stars`, but is built from local data instead of the puzzle website.
+/// It replaces that action, which wrote to the same markers, so this module is the only writer of the table.
+use std::fs;
+
+use crate::template::readme_benchmarks::{locate_table, Error};
+use crate::template::{answers, submission, submission::Verdict};
+use crate::{DaySet, PuzzleId, Year};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// The stars earned for a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stars {
+    pub puzzle: PuzzleId,
+    pub part_1: bool,
+    pub part_2: bool,
+}
+
+impl Stars {
+    #[must_use]
+    pub fn count(&self) -> usize {
+        usize::from(self.part_1) + usize::from(self.part_2)
+    }
+}
+
+/// Reads the stars of a puzzle: a part has a star once an answer was accepted for it,
+/// either stored in its answers file or as a correct verdict in its submission log.
+pub fn read(puzzle: PuzzleId) -> Result<Stars, Error> {
+    let answers = answers::read(puzzle).map_err(|e| Error::Parser(format!("{e:?}")))?;
+    let attempts = submission::read_log(puzzle).map_err(|e| Error::Parser(format!("{e:?}")))?;
+
+    let is_accepted = |part: u8| {
+        answers.get(part).is_some()
+            || attempts.iter().any(|attempt| {
+                attempt.part == part
+                    && matches!(attempt.verdict, Verdict::Correct | Verdict::AlreadySolved)
+            })
+    };
+
+    Ok(Stars {
+        puzzle,
+        part_1: is_accepted(1),
+        part_2: is_accepted(2),
+    })
+}
+
+/// The home year uses the plain marker, other years have their own table, e.g. `<!--- advent_readme_stars table 2022 --->`.
+fn get_marker(year: Year) -> String {
+    if year == Year::home() {
+        MARKER.into()
+    } else {
+        format!("<!--- advent_readme_stars table {year} --->")
+    }
+}
+
+fn construct_table(prefix: &str, year: Year, stars: &[Stars]) -> String {
+    let marker = get_marker(year);
+    let header = format!("{prefix} {year} Results");
+
+    let mut lines: Vec<String> = vec![marker.clone(), header, String::new()];
+    lines.push("| Day | Part 1 | Part 2 |".into());
+    lines.push("| :---: | :---: | :---: |".into());
+
+    // like on the website, days without a star are not listed.
+    for day in stars.iter().filter(|day| day.count() > 0) {
+        let star = |is_earned: bool| if is_earned { "⭐" } else { " " };
+        lines.push(format!(
+            "| [Day {}](https://adventofcode.com/{year}/day/{}) | {} | {} |",
+            day.puzzle.day.into_inner(),
+            day.puzzle.day.into_inner(),
+            star(day.part_1),
+            star(day.part_2),
+        ));
+    }
+
+    let total: usize = stars.iter().map(Stars::count).sum();
+    lines.push(String::new());
+    lines.push(format!("**Total: {total} ⭐**"));
+    lines.push(marker);
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, year: Year, stars: &[Stars]) -> Result<(), Error> {
+    let positions = locate_table(s, &get_marker(year))?;
+    let table = construct_table("##", year, stars);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Updates the stars table of `year` with the stars of every day.
+pub fn update(year: Year) -> Result<(), Error> {
+    let stars = DaySet::all()
+        .iter()
+        .map(|day| read(PuzzleId::new(year, day)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, year, &stars)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{update_content, Stars, MARKER};
+    use crate::{day, year, Day, PuzzleId, Year};
+
+    fn stars(day: Day, part_1: bool, part_2: bool) -> Stars {
+        Stars {
+            puzzle: PuzzleId::new(Year::home(), day),
+            part_1,
+            part_2,
+        }
+    }
+
+    #[test]
+    fn formats_stars() {
+        let mut s = format!("foo\n{MARKER}\n{MARKER}\nbar");
+        let days = [
+            stars(day!(1), true, true),
+            stars(day!(2), false, false),
+            stars(day!(3), true, false),
+        ];
+        update_content(&mut s, Year::home(), &days).unwrap();
+        update_content(&mut s, Year::home(), &days).unwrap();
+
+        let year = Year::home();
+        let expected = [
+            "foo".to_string(),
+            MARKER.to_string(),
+            format!("## {year} Results"),
+            String::new(),
+            "| Day | Part 1 | Part 2 |".to_string(),
+            "| :---: | :---: | :---: |".to_string(),
+            format!("| [Day 1](https://adventofcode.com/{year}/day/1) | ⭐ | ⭐ |"),
+            format!("| [Day 3](https://adventofcode.com/{year}/day/3) | ⭐ |   |"),
+            String::new(),
+            "**Total: 3 ⭐**".to_string(),
+            MARKER.to_string(),
+            "bar".to_string(),
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn updates_table_of_other_year() {
+        let marker = "<!--- advent_readme_stars table 2015 --->";
+        let mut s = format!("{MARKER}\n{marker}\n{marker}");
+        let days = [Stars {
+            puzzle: PuzzleId::new(year!(2015), day!(3)),
+            part_1: true,
+            part_2: false,
+        }];
+        update_content(&mut s, year!(2015), &days).unwrap();
+        assert!(s.starts_with(&format!("{MARKER}\n{marker}\n## 2015 Results")));
+        assert!(s.contains("| [Day 3](https://adventofcode.com/2015/day/3) | ⭐ |   |"));
+    }
+}
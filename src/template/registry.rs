@@ -0,0 +1,170 @@
+/// In-process registry of solutions.
+/// Every `src/bin/NN.rs` and `src/bin/YYYY_NN.rs` is compiled into the main binary as a module and registers its parts here,
+/// so commands can run puzzles without spawning one `cargo` process per day.
+use std::collections::BTreeMap;
+
+use crate::template::protocol::PartRecord;
+use crate::template::runner::{run_part, PartOutput, RunOptions};
+use crate::PuzzleId;
+
+type PartRunner = Box<dyn Fn(&str, &RunOptions) -> PartRecord + Send + Sync>;
+
+/// The type-erased parts of a single puzzle.
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    part_one: PartRunner,
+    part_two: PartRunner,
+}
+
+impl Solution {
+    pub fn new<R1: PartOutput + 'static, R2: PartOutput + 'static>(
+        puzzle: PuzzleId,
+        part_one: fn(&str) -> R1,
+        part_two: fn(&str) -> R2,
+    ) -> Self {
+        Self {
+            puzzle,
+            part_one: Box::new(move |input, options| run_part(part_one, input, puzzle, 1, options)),
+            part_two: Box::new(move |input, options| run_part(part_two, input, puzzle, 2, options)),
+        }
+    }
+
+    /// Runs a single part against `input`, reporting it as configured in `options`.
+    ///
+    /// # Panics
+    /// Panics if `part` is neither `1` nor `2`.
+    pub fn run_part(&self, part: u8, input: &str, options: &RunOptions) -> PartRecord {
+        match part {
+            1 => (self.part_one)(input, options),
+            2 => (self.part_two)(input, options),
+            _ => panic!("expecting a part number of 1 or 2, got {part}"),
+        }
+    }
+
+    /// Runs both parts against `input`, reporting them as configured in `options`.
+    pub fn run(&self, input: &str, options: &RunOptions) -> Vec<PartRecord> {
+        vec![
+            self.run_part(1, input, options),
+            self.run_part(2, input, options),
+        ]
+    }
+}
+
+/// A collection of solutions, keyed by puzzle.
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<PuzzleId, Solution>,
+}
+
+impl Registry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, solution: Solution) {
+        self.solutions.insert(solution.puzzle, solution);
+    }
+
+    #[must_use]
+    pub fn get(&self, puzzle: impl Into<PuzzleId>) -> Option<&Solution> {
+        self.solutions.get(&puzzle.into())
+    }
+
+    /// Iterates registered solutions in order of their year and day.
+    pub fn iter(&self) -> impl Iterator<Item = &Solution> {
+        self.solutions.values()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Registry, Solution};
+    use crate::template::protocol::{OutputFormat, PartStatus};
+    use crate::template::runner::RunOptions;
+    use crate::template::SolutionError;
+    use crate::{day, year, PuzzleId};
+
+    fn part_one(input: &str) -> Option<usize> {
+        Some(input.len())
+    }
+
+    fn part_two(_input: &str) -> Option<u32> {
+        None
+    }
+
+    fn part_panics(_input: &str) -> Option<u32> {
+        panic!("oh no")
+    }
+
+    fn part_fails(input: &str) -> Result<u32, SolutionError> {
+        match input.parse() {
+            Ok(value) => Ok(value),
+            Err(_) => Err(SolutionError::Invalid("not a number".into())),
+        }
+    }
+
+    fn part_unsolved(_input: &str) -> Result<Option<u32>, SolutionError> {
+        Ok(None)
+    }
+
+    fn options() -> RunOptions {
+        RunOptions {
+            format: OutputFormat::Json,
+            ..RunOptions::default()
+        }
+    }
+
+    #[test]
+    fn runs_registered_parts() {
+        let mut registry = Registry::new();
+        registry.register(Solution::new(day!(3).into(), part_one, part_two));
+
+        assert!(registry.get(day!(1)).is_none());
+
+        let records = registry.get(day!(3)).unwrap().run("abcd", &options());
+        assert_eq!(records[0].answer, Some("4".into()));
+        assert_eq!(records[0].status, PartStatus::Solved);
+        assert_eq!(records[1].answer, None);
+        assert_eq!(records[1].status, PartStatus::Unsolved);
+    }
+
+    #[test]
+    fn captures_panics() {
+        let solution = Solution::new(day!(3).into(), part_panics, part_two);
+        let record = solution.run_part(1, "", &options());
+        assert_eq!(record.status, PartStatus::Panicked);
+    }
+
+    #[test]
+    fn reports_errors() {
+        let solution = Solution::new(day!(3).into(), part_fails, part_unsolved);
+
+        let record = solution.run_part(1, "12", &options());
+        assert_eq!(record.status, PartStatus::Solved);
+        assert_eq!(record.answer, Some("12".into()));
+
+        let record = solution.run_part(1, "x", &options());
+        assert_eq!(record.status, PartStatus::Failed);
+        assert_eq!(record.error, Some("invalid input: not a number".into()));
+
+        let record = solution.run_part(2, "x", &options());
+        assert_eq!(record.status, PartStatus::Unsolved);
+    }
+
+    #[test]
+    fn iterates_in_order() {
+        let mut registry = Registry::new();
+        registry.register(Solution::new(day!(5).into(), part_one, part_two));
+        registry.register(Solution::new(day!(2).into(), part_one, part_two));
+        registry.register(Solution::new(
+            PuzzleId::new(year!(2015), day!(9)),
+            part_one,
+            part_two,
+        ));
+        let puzzles: Vec<_> = registry.iter().map(|s| s.puzzle.to_string()).collect();
+        assert_eq!(puzzles[0], "2015/09");
+        assert_eq!(puzzles.len(), 3);
+        assert!(puzzles[1].ends_with("/02") && puzzles[2].ends_with("/05"));
+    }
+}
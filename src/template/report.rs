@@ -0,0 +1,388 @@
+/// Module that renders a run of `all` as a single HTML file: the answers and timings of every day,
+/// bar charts of the time per day and part, and trend lines from the benchmark history.
+/// Styles and charts are inlined, so the file can be shared without any other assets.
+use std::{collections::BTreeMap, fmt::Write, fs, io, path::Path, time::Duration};
+
+use crate::template::history::HistoryEntry;
+use crate::template::protocol::{PartRecord, PartStatus};
+use crate::template::readme_benchmarks::Timings;
+use crate::{Day, Year};
+
+pub static REPORT_PATH: &str = "data/benchmarks/report.html";
+
+const CHART_WIDTH: f64 = 720.0;
+const LABEL_WIDTH: f64 = 64.0;
+const VALUE_WIDTH: f64 = 96.0;
+const BAR_HEIGHT: f64 = 12.0;
+const DAY_GAP: f64 = 8.0;
+
+const TREND_WIDTH: f64 = 160.0;
+const TREND_HEIGHT: f64 = 32.0;
+
+const STYLE: &str = "
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; margin: 2rem auto; max-width: 60rem; color: #1f2328; }
+h1 { margin-bottom: 0.25rem; }
+.summary { color: #59636e; margin-top: 0; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 2rem; }
+th, td { border-bottom: 1px solid #d1d9e0; padding: 0.35rem 0.6rem; text-align: left; }
+td.num { text-align: right; font-variant-numeric: tabular-nums; }
+code { font-family: ui-monospace, Menlo, Consolas, monospace; font-size: 0.9em; }
+.solved { color: #1a7f37; }
+.unsolved { color: #cf222e; }
+.slower { color: #cf222e; }
+.faster { color: #1a7f37; }
+.p1 { fill: #0969da; }
+.p2 { fill: #bf8700; }
+.legend span { display: inline-block; width: 0.8em; height: 0.8em; margin: 0 0.3em 0 1em; }
+svg text { font-size: 11px; fill: #59636e; }
+svg .trend { fill: none; stroke: #0969da; stroke-width: 1.5; }
+";
+
+/// The per-part times of a day, in nanoseconds. Parts without an answer have no time.
+struct DayTimes {
+    day: Day,
+    parts: [Option<u64>; 2],
+}
+
+/// Renders the report of a run of `year`. The times of the charts are taken from the solved parts in `records`,
+/// the trends from the `history` entries of `year` that have at least two runs.
+#[must_use]
+pub fn render(
+    year: Year,
+    timings: &[Timings],
+    records: &[PartRecord],
+    history: &[HistoryEntry],
+) -> String {
+    let total_nanos: f64 = timings.iter().map(|timing| timing.total_nanos).sum();
+    let solved = records
+        .iter()
+        .filter(|record| record.status == PartStatus::Solved)
+        .count();
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    let _ = writeln!(html, "<title>Advent of Code {year} Report</title>");
+    let _ = writeln!(html, "<style>{STYLE}</style>\n</head>\n<body>");
+    let _ = writeln!(html, "<h1>Advent of Code {year}</h1>");
+    let _ = writeln!(
+        html,
+        "<p class=\"summary\">{} day(s), {solved} part(s) solved, {} in total.</p>",
+        timings.len(),
+        format_nanos(total_nanos),
+    );
+
+    html.push_str("<h2>Days</h2>\n");
+    html.push_str(&days_table(timings, records));
+
+    html.push_str("<h2>Time by day and part</h2>\n");
+    html.push_str(
+        "<p class=\"legend\"><span class=\"p1\" style=\"background: #0969da\"></span>Part 1\
+         <span class=\"p2\" style=\"background: #bf8700\"></span>Part 2</p>\n",
+    );
+    html.push_str(&bar_chart(&day_times(timings, records)));
+
+    html.push_str("<h2>Trends</h2>\n");
+    html.push_str(&trends_table(year, history));
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Writes the report to `path`.
+pub fn write_to(path: &Path, html: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, html)
+}
+
+pub fn write(html: &str) -> io::Result<()> {
+    write_to(Path::new(REPORT_PATH), html)
+}
+
+fn days_table(timings: &[Timings], records: &[PartRecord]) -> String {
+    let mut html = String::from(
+        "<table>\n<tr><th>Day</th><th>Title</th><th>Part 1</th><th>Time</th>\
+         <th>Part 2</th><th>Time</th><th>Total</th></tr>\n",
+    );
+
+    for timing in timings {
+        let answer = |part: u8| {
+            records
+                .iter()
+                .find(|record| record.puzzle() == timing.puzzle && record.part == part)
+                .and_then(|record| record.answer.as_deref())
+                .map_or_else(
+                    || "-".to_string(),
+                    |answer| format!("<code>{}</code>", escape(answer)),
+                )
+        };
+        let status = |is_solved: bool| {
+            if is_solved {
+                "<span class=\"solved\">✔</span>"
+            } else {
+                "<span class=\"unsolved\">✖</span>"
+            }
+        };
+
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{} {}</td><td class=\"num\">{}</td>\
+             <td>{} {}</td><td class=\"num\">{}</td><td class=\"num\">{}</td></tr>",
+            timing.puzzle.day.into_inner(),
+            timing.title.as_deref().map_or_else(|| "-".into(), escape),
+            status(timing.part_1_solved),
+            answer(1),
+            escape(timing.part_1.as_deref().unwrap_or("-")),
+            status(timing.part_2_solved),
+            answer(2),
+            escape(timing.part_2.as_deref().unwrap_or("-")),
+            format_nanos(timing.total_nanos),
+        );
+    }
+
+    html.push_str("</table>\n");
+    html
+}
+
+fn day_times(timings: &[Timings], records: &[PartRecord]) -> Vec<DayTimes> {
+    timings
+        .iter()
+        .map(|timing| {
+            let nanos = |part: u8| {
+                records
+                    .iter()
+                    .find(|record| {
+                        record.puzzle() == timing.puzzle
+                            && record.part == part
+                            && record.status == PartStatus::Solved
+                    })
+                    .map(|record| record.duration_nanos)
+            };
+            DayTimes {
+                day: timing.puzzle.day,
+                parts: [nanos(1), nanos(2)],
+            }
+        })
+        .collect()
+}
+
+/// A horizontal bar chart with a bar per part, grouped by day and scaled to the slowest part.
+#[allow(clippy::cast_precision_loss)]
+fn bar_chart(days: &[DayTimes]) -> String {
+    let max_nanos = days
+        .iter()
+        .flat_map(|day| day.parts.iter().flatten())
+        .copied()
+        .max()
+        .unwrap_or(0);
+    let plot_width = CHART_WIDTH - LABEL_WIDTH - VALUE_WIDTH;
+    let day_height = 2.0 * BAR_HEIGHT + DAY_GAP;
+    let height = day_height * days.len() as f64;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{CHART_WIDTH}\" height=\"{height}\" \
+         viewBox=\"0 0 {CHART_WIDTH} {height}\" role=\"img\">"
+    );
+
+    for (i, day) in days.iter().enumerate() {
+        let top = day_height * i as f64;
+        let _ = writeln!(
+            svg,
+            "<text x=\"0\" y=\"{:.1}\">Day {}</text>",
+            top + BAR_HEIGHT + 4.0,
+            day.day.into_inner()
+        );
+
+        for (part, nanos) in day.parts.iter().enumerate() {
+            let y = top + BAR_HEIGHT * part as f64;
+            let Some(nanos) = nanos else {
+                let _ = writeln!(
+                    svg,
+                    "<text x=\"{LABEL_WIDTH}\" y=\"{:.1}\">-</text>",
+                    y + BAR_HEIGHT - 2.0
+                );
+                continue;
+            };
+
+            // bars of parts that took any time are at least a pixel wide, so they stay visible.
+            let width = if max_nanos > 0 {
+                (*nanos as f64 / max_nanos as f64 * plot_width).max(1.0)
+            } else {
+                0.0
+            };
+            let _ = writeln!(
+                svg,
+                "<rect class=\"p{}\" x=\"{LABEL_WIDTH}\" y=\"{y:.1}\" width=\"{width:.1}\" height=\"{:.1}\">\
+                 <title>Day {} Part {}: {}</title></rect>",
+                part + 1,
+                BAR_HEIGHT - 2.0,
+                day.day.into_inner(),
+                part + 1,
+                format_nanos(*nanos as f64),
+            );
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.1}\" y=\"{:.1}\">{}</text>",
+                LABEL_WIDTH + width + 4.0,
+                y + BAR_HEIGHT - 2.0,
+                format_nanos(*nanos as f64),
+            );
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// A table with a trend line of every part of `year` that was benchmarked at least twice.
+fn trends_table(year: Year, history: &[HistoryEntry]) -> String {
+    let mut runs: BTreeMap<(Day, u8), Vec<&HistoryEntry>> = BTreeMap::new();
+    for entry in history.iter().filter(|entry| entry.year == year) {
+        runs.entry((entry.day, entry.part)).or_default().push(entry);
+    }
+    runs.retain(|_, entries| entries.len() > 1);
+
+    if runs.is_empty() {
+        return "<p>Not enough history yet, every <code>cargo time</code> run adds to it.</p>\n"
+            .into();
+    }
+
+    let mut html = String::from(
+        "<table>\n<tr><th>Day</th><th>Part</th><th>Runs</th><th>Trend</th>\
+         <th>First</th><th>Latest</th><th>Change</th></tr>\n",
+    );
+
+    for ((day, part), mut entries) in runs {
+        entries.sort_by_key(|entry| entry.timestamp);
+        let nanos: Vec<u64> = entries.iter().map(|entry| entry.duration_nanos).collect();
+        let (first, latest) = (nanos[0], nanos[nanos.len() - 1]);
+
+        #[allow(clippy::cast_precision_loss)]
+        let change = if first > 0 {
+            (latest as f64 - first as f64) / first as f64 * 100.0
+        } else {
+            0.0
+        };
+        let class = if change > 0.0 { "slower" } else { "faster" };
+
+        #[allow(clippy::cast_precision_loss)]
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{part}</td><td class=\"num\">{}</td><td>{}</td>\
+             <td class=\"num\">{}</td><td class=\"num\">{}</td><td class=\"num {class}\">{change:+.1}%</td></tr>",
+            day.into_inner(),
+            nanos.len(),
+            trend_line(&nanos),
+            format_nanos(first as f64),
+            format_nanos(latest as f64),
+        );
+    }
+
+    html.push_str("</table>\n");
+    html
+}
+
+/// A line through the times of successive runs, scaled between the fastest and the slowest run.
+#[allow(clippy::cast_precision_loss)]
+fn trend_line(nanos: &[u64]) -> String {
+    let min = nanos.iter().copied().min().unwrap_or(0) as f64;
+    let max = nanos.iter().copied().max().unwrap_or(0) as f64;
+    let step = TREND_WIDTH / (nanos.len().max(2) - 1) as f64;
+
+    let points: Vec<String> = nanos
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            // runs that all took the same time are drawn in the middle.
+            let ratio = if max > min {
+                (value as f64 - min) / (max - min)
+            } else {
+                0.5
+            };
+            let y = 2.0 + (1.0 - ratio) * (TREND_HEIGHT - 4.0);
+            format!("{:.1},{y:.1}", step * i as f64)
+        })
+        .collect();
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{TREND_WIDTH}\" height=\"{TREND_HEIGHT}\" \
+         viewBox=\"0 0 {TREND_WIDTH} {TREND_HEIGHT}\"><polyline class=\"trend\" points=\"{}\"/></svg>",
+        points.join(" ")
+    )
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{escape, render, trend_line};
+    use crate::template::protocol::PartStatus;
+    use crate::template::readme_benchmarks::Timings;
+    use crate::template::{history_entry, part_record};
+    use crate::{day, PuzzleId, Year};
+
+    #[test]
+    fn renders_days_charts_and_trends() {
+        let puzzle = PuzzleId::from(day!(1));
+        let timings = vec![Timings {
+            puzzle,
+            title: Some("Trebuchet?!".into()),
+            part_1_solved: true,
+            part_2_solved: false,
+            part_1: Some("2.0ms".into()),
+            part_2: Some("panicked".into()),
+            part_1_alloc: None,
+            part_2_alloc: None,
+            total_nanos: 2e+6,
+        }];
+        let records = vec![
+            part_record(1, Some("<42>"), 2_000_000, PartStatus::Solved),
+            part_record(2, None, 0, PartStatus::Panicked),
+        ];
+        let history = vec![
+            history_entry(puzzle, 1, 2, 2_000_000),
+            history_entry(puzzle, 1, 1, 4_000_000),
+            history_entry(puzzle, 2, 1, 1_000_000),
+        ];
+
+        let html = render(Year::home(), &timings, &records, &history);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(!html.contains("<link") && !html.contains("src=") && !html.contains("<script"));
+        assert!(html.contains("1 day(s), 1 part(s) solved, 2.0ms in total."));
+        assert!(html.contains("<td>Trebuchet?!</td>"));
+        assert!(html.contains("<code>&lt;42&gt;</code>"));
+        assert!(html.contains("<rect class=\"p1\""));
+        assert!(!html.contains("<rect class=\"p2\""));
+        // part 2 has a single run, which is not a trend yet.
+        assert!(html.contains("<tr><td>1</td><td>1</td><td class=\"num\">2</td>"));
+        assert!(html.contains("<td class=\"num faster\">-50.0%</td>"));
+        assert!(!html.contains("<tr><td>1</td><td>2</td>"));
+    }
+
+    #[test]
+    fn draws_trend_lines() {
+        assert!(trend_line(&[4, 2, 3]).contains("points=\"0.0,2.0 80.0,30.0 160.0,16.0\""));
+        assert!(trend_line(&[5, 5]).contains("points=\"0.0,16.0 160.0,16.0\""));
+    }
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(
+            escape("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+    }
+}
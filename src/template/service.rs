@@ -0,0 +1,211 @@
+/// Abstraction over the Advent of Code website.
+/// The default implementation calls aoc-cli, a file-backed fake allows running the commands offline.
+use std::{
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process,
+};
+
+use crate::template::submission::Verdict;
+use crate::template::{answers, aoc_cli};
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum ServiceError {
+    Aoc(aoc_cli::AocCommandError),
+    IO(io::Error),
+}
+
+impl From<aoc_cli::AocCommandError> for ServiceError {
+    fn from(e: aoc_cli::AocCommandError) -> Self {
+        ServiceError::Aoc(e)
+    }
+}
+
+impl From<io::Error> for ServiceError {
+    fn from(e: io::Error) -> Self {
+        ServiceError::IO(e)
+    }
+}
+
+impl Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ServiceError::Aoc(e) => write!(f, "failed to call aoc-cli: {e}"),
+            ServiceError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// The operations the commands need from the puzzle website.
+pub trait PuzzleService {
+    /// Describes the service in messages, e.g. `aoc-cli`.
+    fn name(&self) -> String;
+
+    /// Returns the personal input for a puzzle.
+    fn fetch_input(&self, puzzle: PuzzleId) -> Result<String, ServiceError>;
+
+    /// Returns the description of a puzzle as markdown.
+    fn fetch_description(&self, puzzle: PuzzleId) -> Result<String, ServiceError>;
+
+    /// Submits an answer and returns the verdict of the website.
+    fn submit_answer(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<Verdict, ServiceError>;
+}
+
+/// Selects the service to use: the fake if `AOC_FAKE_DIR` is set, aoc-cli otherwise.
+pub fn from_env() -> Result<Box<dyn PuzzleService>, ServiceError> {
+    if let Ok(dir) = env::var("AOC_FAKE_DIR") {
+        return Ok(Box::new(FakeService::new(dir)));
+    }
+
+    aoc_cli::check()?;
+    Ok(Box::new(AocCli))
+}
+
+/// Like [`from_env`], but exits with a hint if aoc-cli is missing.
+pub fn from_env_or_exit() -> Box<dyn PuzzleService> {
+    from_env().unwrap_or_else(|_| {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Talks to the website through the "aoc-cli" command-line.
+pub struct AocCli;
+
+impl PuzzleService for AocCli {
+    fn name(&self) -> String {
+        "aoc-cli".into()
+    }
+
+    fn fetch_input(&self, puzzle: PuzzleId) -> Result<String, ServiceError> {
+        Ok(aoc_cli::download_input(puzzle)?)
+    }
+
+    fn fetch_description(&self, puzzle: PuzzleId) -> Result<String, ServiceError> {
+        Ok(aoc_cli::download_puzzle(puzzle)?)
+    }
+
+    fn submit_answer(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<Verdict, ServiceError> {
+        Ok(aoc_cli::submit(puzzle, part, answer)?)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Serves fixtures from a local directory and records submissions, for testing without network.
+///
+/// The directory mirrors the layout of `data`:
+///  - `inputs/NN.txt` and `puzzles/NN.md` are served as input and description.
+///  - `answers/NN.toml` holds the answers that the fake accepts, wrong numbers are judged too high or too low.
+///  - every submission is appended to `submissions.txt` as `year/day part answer`.
+pub struct FakeService {
+    root: PathBuf,
+}
+
+/// A submission received by the [`FakeService`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub answer: String,
+}
+
+impl FakeService {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The path of a fixture, laid out like the files in `data`.
+    fn path(&self, folder: &str, puzzle: PuzzleId, extension: &str) -> PathBuf {
+        self.root
+            .join(puzzle.data_folder(folder))
+            .join(format!("{}.{extension}", puzzle.day))
+    }
+
+    fn submissions_path(&self) -> PathBuf {
+        self.root.join("submissions.txt")
+    }
+
+    /// Returns every submission received so far.
+    pub fn submissions(&self) -> Result<Vec<Submission>, ServiceError> {
+        let path = self.submissions_path();
+        if !path.exists() {
+            return Ok(vec![]);
+        }
+
+        Ok(fs::read_to_string(path)?
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.splitn(3, ' ');
+                Some(Submission {
+                    puzzle: parts.next()?.parse().ok()?,
+                    part: parts.next()?.parse().ok()?,
+                    answer: parts.next()?.to_string(),
+                })
+            })
+            .collect())
+    }
+}
+
+impl PuzzleService for FakeService {
+    fn name(&self) -> String {
+        format!("the fake service in \"{}\"", self.root.display())
+    }
+
+    fn fetch_input(&self, puzzle: PuzzleId) -> Result<String, ServiceError> {
+        Ok(fs::read_to_string(self.path("inputs", puzzle, "txt"))?)
+    }
+
+    fn fetch_description(&self, puzzle: PuzzleId) -> Result<String, ServiceError> {
+        Ok(fs::read_to_string(self.path("puzzles", puzzle, "md"))?)
+    }
+
+    fn submit_answer(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<Verdict, ServiceError> {
+        fs::create_dir_all(&self.root)?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.submissions_path())?;
+        writeln!(file, "{puzzle} {part} {answer}")?;
+
+        let accepted = answers::read_from(&self.path("answers", puzzle, "toml"))
+            .ok()
+            .and_then(|answers| answers.get(part).map(ToString::to_string));
+
+        let Some(accepted) = accepted else {
+            return Ok(Verdict::Incorrect);
+        };
+
+        if accepted == answer {
+            return Ok(Verdict::Correct);
+        }
+
+        // numeric answers get a hint in which direction they are off, like on the website.
+        Ok(match (answer.parse::<i64>(), accepted.parse::<i64>()) {
+            (Ok(answer), Ok(accepted)) if answer > accepted => Verdict::TooHigh,
+            (Ok(_), Ok(_)) => Verdict::TooLow,
+            _ => Verdict::Incorrect,
+        })
+    }
+}
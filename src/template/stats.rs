@@ -0,0 +1,140 @@
+/// Summary statistics for benchmark samples.
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Statistics of a benchmark run, computed after outliers have been rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchStats {
+    pub min_nanos: u64,
+    pub median_nanos: u64,
+    pub mean_nanos: u64,
+    pub p95_nanos: u64,
+    pub std_dev_nanos: u64,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: u64,
+}
+
+impl BenchStats {
+    /// Computes statistics for the provided samples. Returns [`None`] if there are no samples.
+    ///
+    /// Samples outside of Tukey's fences (1.5 times the interquartile range below the first or above
+    /// the third quartile) are treated as outliers and do not contribute to the statistics.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        sorted.sort_by(f64::total_cmp);
+
+        let q1 = percentile(&sorted, 25.0)?;
+        let q3 = percentile(&sorted, 75.0)?;
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        let kept: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|x| (low..=high).contains(x))
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let n = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / n;
+        let variance = if kept.len() > 1 {
+            kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            min_nanos: to_nanos(*kept.first()?),
+            median_nanos: to_nanos(median(&kept)?),
+            mean_nanos: to_nanos(mean),
+            p95_nanos: to_nanos(percentile(&kept, 95.0)?),
+            std_dev_nanos: to_nanos(variance.sqrt()),
+            outliers: (sorted.len() - kept.len()) as u64,
+        })
+    }
+
+    #[must_use]
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_nanos)
+    }
+
+    #[must_use]
+    pub fn std_dev(&self) -> Duration {
+        Duration::from_nanos(self.std_dev_nanos)
+    }
+}
+
+/// Nearest-rank percentile of an ascending slice.
+fn percentile(sorted: &[f64], p: f64) -> Option<f64> {
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted.get(rank.saturating_sub(1)).copied()
+}
+
+fn median(sorted: &[f64]) -> Option<f64> {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        Some((sorted.get(mid.checked_sub(1)?)? + sorted.get(mid)?) / 2.0)
+    } else {
+        sorted.get(mid).copied()
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_nanos(secs: f64) -> u64 {
+    (secs * 1e9).round() as u64
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::BenchStats;
+    use std::time::Duration;
+
+    fn samples(nanos: &[u64]) -> Vec<Duration> {
+        nanos.iter().map(|n| Duration::from_nanos(*n)).collect()
+    }
+
+    #[test]
+    fn empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = BenchStats::from_samples(&samples(&[100])).unwrap();
+        assert_eq!(stats.min_nanos, 100);
+        assert_eq!(stats.median_nanos, 100);
+        assert_eq!(stats.mean_nanos, 100);
+        assert_eq!(stats.p95_nanos, 100);
+        assert_eq!(stats.std_dev_nanos, 0);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&samples(&[40, 10, 30, 20])).unwrap();
+        assert_eq!(stats.min_nanos, 10);
+        assert_eq!(stats.median_nanos, 25);
+        assert_eq!(stats.mean_nanos, 25);
+        assert_eq!(stats.p95_nanos, 40);
+        assert_eq!(stats.std_dev_nanos, 13);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats =
+            BenchStats::from_samples(&samples(&[10, 11, 10, 12, 11, 10, 11, 1000])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.min_nanos, 10);
+        assert_eq!(stats.median_nanos, 11);
+        assert_eq!(stats.mean_nanos, 11);
+        assert_eq!(stats.p95_nanos, 12);
+    }
+}
@@ -0,0 +1,372 @@
+/// Module that interprets the responses of the puzzle website to submitted answers,
+/// and keeps a log of every attempt to refuse submissions that are known to be wrong.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::get_data_path;
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// The verdict of the puzzle website on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without a hint in which direction.
+    Incorrect,
+    /// The answer was not checked because the last submission was too recent.
+    RateLimited(Duration),
+    AlreadySolved,
+    /// The website does not take an answer for the part, e.g. part two before part one was solved
+    /// or a part that was solved before.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Parses the response text of a submission, returns [`None`] if the response is not recognized.
+    #[must_use]
+    pub fn from_response(response: &str) -> Option<Self> {
+        let response = response.to_lowercase();
+
+        if response.contains("that's the right answer") {
+            Some(Self::Correct)
+        } else if response.contains("your answer is too high") {
+            Some(Self::TooHigh)
+        } else if response.contains("your answer is too low") {
+            Some(Self::TooLow)
+        } else if response.contains("that's not the right answer") {
+            Some(Self::Incorrect)
+        } else if response.contains("you gave an answer too recently") {
+            Some(Self::RateLimited(parse_wait(&response).unwrap_or_default()))
+        } else if response.contains("solving the right level") || response.contains("locked") {
+            // checked first, as the wrong level response asks "did you already complete it?".
+            Some(Self::WrongLevel)
+        } else if response.contains("already complete") {
+            Some(Self::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn is_correct(self) -> bool {
+        self == Self::Correct
+    }
+
+    /// Whether the website judged the answer to be wrong.
+    #[must_use]
+    pub fn is_rejection(self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Incorrect)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => f.write_str("correct"),
+            Self::TooHigh => f.write_str("too high"),
+            Self::TooLow => f.write_str("too low"),
+            Self::Incorrect => f.write_str("incorrect"),
+            Self::RateLimited(wait) => write!(f, "rate limited, wait {}s", wait.as_secs()),
+            Self::AlreadySolved => f.write_str("already solved"),
+            Self::WrongLevel => f.write_str("wrong level"),
+        }
+    }
+}
+
+/// Parses the remaining wait time from e.g. "You have 1m 3s left to wait."
+fn parse_wait(response: &str) -> Option<Duration> {
+    let (before, _) = response.split_once("left to wait")?;
+    let (_, amount) = before.rsplit_once("you have")?;
+
+    amount
+        .split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A single submitted answer and the verdict it received.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// The reason why a submission was refused before it was sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The same answer was already rejected.
+    Rejected(Verdict),
+    /// The answer is at or above an answer that was too high.
+    AboveBound(String),
+    /// The answer is at or below an answer that was too low.
+    BelowBound(String),
+    /// The website would not accept a submission yet.
+    Cooldown(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Rejected(verdict) => {
+                write!(f, "this answer was already rejected as {verdict}")
+            }
+            Refusal::AboveBound(bound) => write!(f, "{bound} was already too high"),
+            Refusal::BelowBound(bound) => write!(f, "{bound} was already too low"),
+            Refusal::Cooldown(wait) => write!(f, "still locked out for {}s", wait.as_secs()),
+        }
+    }
+}
+
+/// Returns the current time in seconds since the unix epoch.
+#[must_use]
+pub fn timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// The lockout after the n-th wrong answer: one minute at first, five minutes once guesses pile up.
+fn lockout(wrong_answers: usize) -> Duration {
+    if wrong_answers < 4 {
+        Duration::from_secs(60)
+    } else {
+        Duration::from_secs(5 * 60)
+    }
+}
+
+/// Checks whether `answer` may be submitted for `part`, given the previous attempts for the puzzle.
+pub fn check(attempts: &[Attempt], part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+    let attempts: Vec<&Attempt> = attempts.iter().filter(|a| a.part == part).collect();
+
+    if let Some(attempt) = attempts
+        .iter()
+        .find(|a| a.answer == answer && a.verdict.is_rejection())
+    {
+        return Err(Refusal::Rejected(attempt.verdict));
+    }
+
+    if let Ok(value) = answer.parse::<i128>() {
+        let bound = |verdict: Verdict| {
+            attempts
+                .iter()
+                .filter(move |a| a.verdict == verdict)
+                .filter_map(|a| a.answer.parse::<i128>().ok())
+        };
+
+        if let Some(upper) = bound(Verdict::TooHigh)
+            .min()
+            .filter(|&upper| value >= upper)
+        {
+            return Err(Refusal::AboveBound(upper.to_string()));
+        }
+        if let Some(lower) = bound(Verdict::TooLow).max().filter(|&lower| value <= lower) {
+            return Err(Refusal::BelowBound(lower.to_string()));
+        }
+    }
+
+    let mut locked_until = 0;
+    let mut wrong_answers = 0;
+    for attempt in &attempts {
+        let wait = match attempt.verdict {
+            Verdict::RateLimited(wait) => wait,
+            verdict if verdict.is_rejection() => {
+                wrong_answers += 1;
+                lockout(wrong_answers)
+            }
+            _ => continue,
+        };
+        locked_until = locked_until.max(attempt.timestamp + wait.as_secs());
+    }
+
+    if locked_until > now {
+        return Err(Refusal::Cooldown(Duration::from_secs(locked_until - now)));
+    }
+
+    Ok(())
+}
+
+#[must_use]
+pub fn get_path_for_log(puzzle: PuzzleId) -> PathBuf {
+    get_data_path("submissions", puzzle, &format!("{}.jsonl", puzzle.day))
+}
+
+/// Reads every attempt for a puzzle. Puzzles without a log have no attempts.
+pub fn read_log(puzzle: PuzzleId) -> Result<Vec<Attempt>, Error> {
+    let path = get_path_for_log(puzzle);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    fs::read_to_string(path)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(|e| Error::Parser(e.to_string())))
+        .collect()
+}
+
+/// Appends an attempt to the puzzle's log.
+pub fn append_to_log(puzzle: PuzzleId, attempt: &Attempt) -> Result<(), Error> {
+    let path = get_path_for_log(puzzle);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let line = serde_json::to_string(attempt).map_err(|e| Error::Parser(e.to_string()))?;
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{check, Attempt, Refusal, Verdict};
+
+    fn attempt(part: u8, answer: &str, verdict: Verdict, timestamp: u64) -> Attempt {
+        Attempt {
+            part,
+            answer: answer.into(),
+            verdict,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        let cases = [
+            ("That's the right answer! You are one gold star closer to saving Christmas.", Verdict::Correct),
+            ("That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data.", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low. Please wait one minute before trying again.", Verdict::TooLow),
+            ("That's not the right answer. If you're stuck, make sure you're using the full input data.", Verdict::Incorrect),
+            ("You don't seem to be solving the right level.", Verdict::WrongLevel),
+            ("This puzzle part is already complete.", Verdict::AlreadySolved),
+        ];
+
+        for (response, verdict) in cases {
+            assert_eq!(
+                Verdict::from_response(response),
+                Some(verdict),
+                "{response}"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_wrong_level() {
+        // verbatim, the wrong level response also asks whether the part was already completed.
+        let response = "You don't seem to be solving the right level.  Did you already complete it? [Return to Day 1]";
+        assert_eq!(Verdict::from_response(response), Some(Verdict::WrongLevel));
+    }
+
+    #[test]
+    fn parses_rate_limit() {
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 3s left to wait."),
+            Some(Verdict::RateLimited(Duration::from_secs(63)))
+        );
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 34s left to wait."),
+            Some(Verdict::RateLimited(Duration::from_secs(34)))
+        );
+    }
+
+    #[test]
+    fn ignores_unknown_responses() {
+        assert_eq!(Verdict::from_response("[INFO  aoc] 🎄 aoc-cli"), None);
+    }
+
+    #[test]
+    fn refuses_rejected_answers() {
+        let attempts = [attempt(1, "abc", Verdict::Incorrect, 0)];
+        assert_eq!(
+            check(&attempts, 1, "abc", 1000),
+            Err(Refusal::Rejected(Verdict::Incorrect))
+        );
+        assert_eq!(check(&attempts, 1, "abd", 1000), Ok(()));
+        assert_eq!(check(&attempts, 2, "abc", 1000), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let attempts = [
+            attempt(1, "100", Verdict::TooHigh, 0),
+            attempt(1, "150", Verdict::TooHigh, 0),
+            attempt(1, "10", Verdict::TooLow, 0),
+        ];
+        assert_eq!(
+            check(&attempts, 1, "120", 1000),
+            Err(Refusal::AboveBound("100".into()))
+        );
+        assert_eq!(
+            check(&attempts, 1, "-5", 1000),
+            Err(Refusal::BelowBound("10".into()))
+        );
+        assert_eq!(check(&attempts, 1, "50", 1000), Ok(()));
+    }
+
+    #[test]
+    fn refuses_during_cooldown() {
+        let attempts = [attempt(1, "1", Verdict::Incorrect, 1000)];
+        assert_eq!(
+            check(&attempts, 1, "2", 1030),
+            Err(Refusal::Cooldown(Duration::from_secs(30)))
+        );
+        assert_eq!(check(&attempts, 1, "2", 1060), Ok(()));
+
+        let attempts = [attempt(
+            1,
+            "1",
+            Verdict::RateLimited(Duration::from_secs(63)),
+            1000,
+        )];
+        assert_eq!(
+            check(&attempts, 1, "1", 1003),
+            Err(Refusal::Cooldown(Duration::from_secs(60)))
+        );
+
+        let attempts: Vec<Attempt> = (0..4)
+            .map(|i| attempt(1, &i.to_string(), Verdict::Incorrect, 1000))
+            .collect();
+        assert_eq!(
+            check(&attempts, 1, "9", 1100),
+            Err(Refusal::Cooldown(Duration::from_secs(200)))
+        );
+    }
+}
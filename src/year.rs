@@ -0,0 +1,154 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// A year in which advent of code took place (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2022).unwrap();
+/// assert_eq!(year.to_string(), "2022")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u16", into = "u16")]
+pub struct Year(u16);
+
+/// The year configured with `AOC_YEAR` in `.cargo/config.toml`.
+const HOME: Year = match option_env!("AOC_YEAR") {
+    Some(year) => parse_const(year),
+    None => DEFAULT_HOME,
+};
+
+/// The home year of builds that do not pick up `.cargo/config.toml`,
+/// e.g. `cargo` run from outside of the repository or a crate that depends on this one.
+const DEFAULT_HOME: Year = Year(2023);
+
+const fn parse_const(s: &str) -> Year {
+    let bytes = s.as_bytes();
+    let mut year: u16 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        assert!(
+            bytes[i].is_ascii_digit() && i < 4,
+            "expecting AOC_YEAR to be a four digit year"
+        );
+        year = year * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+    assert!(year >= 2015, "expecting AOC_YEAR to be 2015 or later");
+    Year(year)
+}
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's a year of advent of code,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(2015..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// The year configured with `AOC_YEAR`. Its puzzles live at the top level of `src/bin` and `data`.
+    pub const fn home() -> Self {
+        HOME
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl TryFrom<u16> for Year {
+    type Error = YearFromStrError;
+
+    fn try_from(year: u16) -> Result<Self, Self::Error> {
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+impl From<Year> for u16 {
+    fn from(year: Year) -> Self {
+        year.into_inner()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015 && $year <= 9999,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a year of 2015 or later"
+            ),
+        );
+        $crate::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2022".parse::<Year>().unwrap(), year!(2022));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("22".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn reads_home_year() {
+        assert_eq!(Year::home().to_string(), std::env::var("AOC_YEAR").unwrap());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
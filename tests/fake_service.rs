@@ -0,0 +1,170 @@
+/// Runs the commands that talk to Advent of Code against the fake service of `AOC_FAKE_DIR`,
+/// both through the library and end to end through the main binary.
+use std::{
+    fs,
+    path::Path,
+    process::{Command, Output},
+};
+
+use advent_of_code::template::commands::{download, read};
+use advent_of_code::template::service::{FakeService, PuzzleService, Submission};
+use advent_of_code::template::submission::Verdict;
+use advent_of_code::template::{fixture_dir, write_fixture};
+use advent_of_code::{day, year, PuzzleId};
+
+/// Runs the main binary in `root` with the fake service serving `fixtures`.
+fn run_binary(root: &Path, fixtures: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_advent_of_code"))
+        .args(args)
+        .current_dir(root)
+        .env("AOC_FAKE_DIR", fixtures)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn serves_fixtures() {
+    let dir = fixture_dir("serves_fixtures");
+    write_fixture(dir.join("inputs").join("03.txt"), "1\n2\n");
+    write_fixture(dir.join("2022").join("inputs").join("03.txt"), "3\n");
+
+    let service = FakeService::new(&dir);
+    assert_eq!(service.fetch_input(day!(3).into()).unwrap(), "1\n2\n");
+    assert_eq!(
+        service
+            .fetch_input(PuzzleId::new(year!(2022), day!(3)))
+            .unwrap(),
+        "3\n"
+    );
+    assert!(service.fetch_description(day!(3).into()).is_err());
+}
+
+#[test]
+fn records_submissions() {
+    let dir = fixture_dir("records_submissions");
+    write_fixture(dir.join("answers").join("03.toml"), "part_one = \"42\"");
+
+    let service = FakeService::new(&dir);
+    let puzzle = PuzzleId::from(day!(3));
+    assert_eq!(
+        service.submit_answer(puzzle, 1, "41").unwrap(),
+        Verdict::TooLow
+    );
+    assert_eq!(
+        service.submit_answer(puzzle, 1, "42").unwrap(),
+        Verdict::Correct
+    );
+    assert_eq!(
+        service.submit_answer(puzzle, 2, "42").unwrap(),
+        Verdict::Incorrect
+    );
+
+    let submission = |part: u8, answer: &str| Submission {
+        puzzle,
+        part,
+        answer: answer.into(),
+    };
+    assert_eq!(
+        service.submissions().unwrap(),
+        vec![
+            submission(1, "41"),
+            submission(1, "42"),
+            submission(2, "42")
+        ]
+    );
+}
+
+#[test]
+fn downloads_input_and_puzzle() {
+    let fixtures = fixture_dir("download_fixtures");
+    write_fixture(
+        fixtures.join("2022").join("inputs").join("05.txt"),
+        "seeds: 1 2",
+    );
+    write_fixture(
+        fixtures.join("2022").join("puzzles").join("05.md"),
+        "## Day 5",
+    );
+
+    let data = fixture_dir("download_data");
+    let puzzle = PuzzleId::new(year!(2022), day!(5));
+    download::run(&FakeService::new(&fixtures), puzzle, &data).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(data.join("2022").join("inputs").join("05.txt")).unwrap(),
+        "seeds: 1 2"
+    );
+    assert_eq!(
+        fs::read_to_string(data.join("2022").join("puzzles").join("05.md")).unwrap(),
+        "## Day 5"
+    );
+
+    // the `download` command writes to the `data` folder of the working directory.
+    let root = fixture_dir("download_root");
+    let output = run_binary(&root, &fixtures, &["download", "2022/05"]);
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(root.join("data/2022/inputs/05.txt")).unwrap(),
+        "seeds: 1 2"
+    );
+}
+
+#[test]
+fn prints_description() {
+    let fixtures = fixture_dir("read_fixtures");
+    write_fixture(fixtures.join("puzzles").join("07.md"), "## Day 7\n\n");
+
+    let mut out = Vec::new();
+    read::run(&FakeService::new(&fixtures), day!(7).into(), &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "## Day 7\n");
+
+    let root = fixture_dir("read_root");
+    let output = run_binary(&root, &fixtures, &["read", "07"]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "## Day 7\n");
+}
+
+#[test]
+fn submits_answers() {
+    let fixtures = fixture_dir("submit_fixtures");
+    write_fixture(
+        fixtures.join("answers").join("01.toml"),
+        "part_one = \"142\"",
+    );
+
+    // day 1 adds up the first and last digit of every line.
+    let root = fixture_dir("submit_root");
+    write_fixture(
+        root.join("data/inputs/01.txt"),
+        "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n",
+    );
+
+    // runs in-process only if the profile matches the binary, which is built like this test.
+    let mut args = vec!["solve", "01", "--submit", "1"];
+    if !cfg!(debug_assertions) {
+        args.push("--release");
+    }
+    let output = run_binary(&root, &fixtures, &args);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains(&format!(
+            "Submitting result via the fake service in \"{}\"",
+            fixtures.display()
+        )),
+        "{stdout}"
+    );
+
+    let service = FakeService::new(&fixtures);
+    assert_eq!(
+        service.submissions().unwrap(),
+        vec![Submission {
+            puzzle: day!(1).into(),
+            part: 1,
+            answer: "142".into(),
+        }]
+    );
+    assert!(fs::read_to_string(root.join("data/answers/01.toml"))
+        .unwrap()
+        .contains("142"));
+}